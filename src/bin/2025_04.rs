@@ -0,0 +1,60 @@
+use advent_of_code::BitGrid;
+
+advent_of_code::solution!(4);
+
+const MOVEABLE_PAPER_LIMIT: u32 = 4; // less than this amount
+
+fn parse_paper(input: &str) -> BitGrid {
+    BitGrid::parse(input, |b| match b {
+        b'@' => Some(true),
+        b'.' => Some(false),
+        _ => None,
+    })
+    .unwrap_or_else(|e| panic!("Invalid input: {e}"))
+}
+
+fn moveable_papers(paper: &BitGrid) -> BitGrid {
+    let mut moveable = paper.fewer_neighbors_than(MOVEABLE_PAPER_LIMIT);
+    moveable &= paper;
+    moveable
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    Some(moveable_papers(&parse_paper(input)).count_ones())
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let mut paper = parse_paper(input);
+    let mut removed_count = 0;
+
+    // removing paper only frees up its neighbors, so removing all moveable papers at once
+    // ends with the same count as removing them one by one.
+    // ANIMATE: animate removal passes
+    loop {
+        let moveable = moveable_papers(&paper);
+        if !moveable.any() {
+            break;
+        }
+        removed_count += moveable.count_ones();
+        paper -= &moveable;
+    }
+
+    Some(removed_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, Some(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, Some(43));
+    }
+}
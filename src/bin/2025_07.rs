@@ -1,3 +1,4 @@
+use advent_of_code::Grid;
 use hashbrown::{HashMap, HashSet};
 use nohash::BuildNoHashHasher;
 use std::mem;
@@ -11,34 +12,25 @@ enum TileType {
     Splitter,
 }
 
-fn parse_lines(input: &str) -> Vec<Vec<TileType>> {
-    input
-        .lines()
-        .map(|l| {
-            l.as_bytes()
-                .iter()
-                .map(|&b| match b {
-                    b'.' => TileType::Empty,
-                    b'S' => TileType::Start,
-                    b'^' => TileType::Splitter,
-                    _ => unreachable!("invalid input"),
-                })
-                .collect()
-        })
-        .collect()
+fn parse_grid(input: &str) -> Grid<TileType> {
+    Grid::parse(input, |b| match b {
+        b'.' => Some(TileType::Empty),
+        b'S' => Some(TileType::Start),
+        b'^' => Some(TileType::Splitter),
+        _ => None,
+    })
+    .unwrap_or_else(|e| panic!("invalid input: {e}"))
 }
 
-fn find_start_index(tachyon_manifold: &[Vec<TileType>]) -> usize {
+fn find_start_index(tachyon_manifold: &Grid<TileType>) -> usize {
     tachyon_manifold
-        .first()
-        .unwrap()
-        .iter()
-        .position(|&t| t == TileType::Start)
+        .row(0)
+        .and_then(|line| line.iter().position(|&t| t == TileType::Start))
         .expect("No start found in first line")
 }
 
 pub fn part_one(input: &str) -> Option<usize> {
-    let tachyon_manifold = parse_lines(input);
+    let tachyon_manifold = parse_grid(input);
     let start_index = find_start_index(&tachyon_manifold);
 
     let mut visited = 0;
@@ -49,7 +41,7 @@ pub fn part_one(input: &str) -> Option<usize> {
         HashSet::with_hasher(BuildNoHashHasher::default());
     current_blocks.insert(start_index);
 
-    tachyon_manifold.iter().skip(1).for_each(|current_line| {
+    tachyon_manifold.rows().skip(1).for_each(|current_line| {
         next_blocks.clear();
 
         for &index in &current_blocks {
@@ -72,7 +64,7 @@ pub fn part_one(input: &str) -> Option<usize> {
 }
 
 pub fn part_two(input: &str) -> Option<usize> {
-    let tachyon_manifold = parse_lines(input);
+    let tachyon_manifold = parse_grid(input);
     let start_index = find_start_index(&tachyon_manifold);
 
     let mut current_blocks: HashMap<usize, usize, BuildNoHashHasher<usize>> =
@@ -81,7 +73,7 @@ pub fn part_two(input: &str) -> Option<usize> {
         HashMap::with_hasher(BuildNoHashHasher::default());
     current_blocks.insert(start_index, 1);
 
-    tachyon_manifold.iter().skip(1).for_each(|current_line| {
+    tachyon_manifold.rows().skip(1).for_each(|current_line| {
         next_blocks.clear();
 
         for (&index, &size) in &current_blocks {
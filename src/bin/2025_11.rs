@@ -161,30 +161,17 @@ fn state_key(node: u16, dac_seen: bool, fft_seen: bool) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::template::{Part, read_example};
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&read_example(DAY, Part::One));
         assert_eq!(result, Some(5));
     }
 
     #[test]
     fn test_part_two() {
-        let example = "svr: aaa bbb
-aaa: fft
-fft: ccc
-bbb: tty
-tty: ccc
-ccc: ddd eee
-ddd: hub
-hub: fff
-eee: dac
-dac: fff
-fff: ggg hhh
-ggg: out
-hhh: out";
-        // let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        let result = part_two(example);
+        let result = part_two(&read_example(DAY, Part::Two));
         assert_eq!(result, Some(2));
     }
 }
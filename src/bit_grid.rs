@@ -0,0 +1,471 @@
+use std::ops::{BitAnd, BitAndAssign, BitOrAssign, SubAssign};
+
+use crate::{Coord, Grid, ParseGridError, grid::parse_rows};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A rectangular grid of booleans, packed into 64 cells per word.
+/// Every row starts at a new word, bit `x % 64` of word `x / 64` is the cell in column `x`.
+/// Bits past the width of a row are always zero.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    /// Create a grid with all cells unset
+    pub fn new(width: usize, height: usize) -> Self {
+        let words_per_row = width.div_ceil(WORD_BITS);
+        BitGrid {
+            width,
+            height,
+            words_per_row,
+            words: vec![0; words_per_row * height],
+        }
+    }
+
+    /// Create a grid with the cells of `grid` set that match `predicate`
+    pub fn from_grid<T>(grid: &Grid<T>, mut predicate: impl FnMut(&T) -> bool) -> Self {
+        let mut bits = BitGrid::new(grid.width(), grid.height());
+        for (y, row) in grid.rows().enumerate() {
+            let words = bits.row_mut(y);
+            for (x, cell) in row.iter().enumerate() {
+                if predicate(cell) {
+                    words[x / WORD_BITS] |= 1 << (x % WORD_BITS);
+                }
+            }
+        }
+        bits
+    }
+
+    /// Parse a grid with one row per line, like `Grid::parse`, without creating a generic grid first
+    pub fn parse(
+        input: &str,
+        mut is_set: impl FnMut(u8) -> Option<bool>,
+    ) -> Result<Self, ParseGridError> {
+        let (width, rows) = parse_rows(input)?;
+
+        let mut bits = BitGrid::new(width, rows.len());
+        for (y, row) in rows.iter().enumerate() {
+            let words = bits.row_mut(y);
+            for (x, &byte) in row.iter().enumerate() {
+                let set = is_set(byte).ok_or(ParseGridError::UnexpectedByte {
+                    byte,
+                    coord: Coord::new(x as i32, y as i32),
+                })?;
+                words[x / WORD_BITS] |= u64::from(set) << (x % WORD_BITS);
+            }
+        }
+
+        Ok(bits)
+    }
+
+    /// Create a generic grid with the same cells
+    pub fn to_grid(&self) -> Grid<bool> {
+        let cells = (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| self.bit(x, y)))
+            .collect();
+        Grid::new(self.width, self.height, cells)
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns whether `coord` is inside the grid
+    #[inline]
+    pub fn contains(&self, coord: Coord) -> bool {
+        self.position(coord).is_some()
+    }
+
+    #[inline]
+    fn position(&self, coord: Coord) -> Option<(usize, usize)> {
+        let x = usize::try_from(coord.x())
+            .ok()
+            .filter(|&x| x < self.width)?;
+        let y = usize::try_from(coord.y())
+            .ok()
+            .filter(|&y| y < self.height)?;
+        Some((x, y))
+    }
+
+    #[inline]
+    fn row(&self, y: usize) -> &[u64] {
+        &self.words[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+
+    #[inline]
+    fn row_mut(&mut self, y: usize) -> &mut [u64] {
+        &mut self.words[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+
+    #[inline]
+    fn bit(&self, x: usize, y: usize) -> bool {
+        self.row(y)[x / WORD_BITS] >> (x % WORD_BITS) & 1 == 1
+    }
+
+    /// Returns whether the cell at `coord` is set. Cells outside of the grid are unset.
+    #[inline]
+    pub fn get(&self, coord: Coord) -> bool {
+        self.position(coord).is_some_and(|(x, y)| self.bit(x, y))
+    }
+
+    /// Set or unset the cell at `coord`. Panics if `coord` is outside of the grid.
+    #[inline]
+    pub fn set(&mut self, coord: Coord, value: bool) {
+        let Some((x, y)) = self.position(coord) else {
+            panic!(
+                "({}, {}) is outside of the {}x{} grid",
+                coord.x(),
+                coord.y(),
+                self.width,
+                self.height
+            );
+        };
+        let mask = 1 << (x % WORD_BITS);
+        let word = &mut self.row_mut(y)[x / WORD_BITS];
+        if value {
+            *word |= mask;
+        } else {
+            *word &= !mask;
+        }
+    }
+
+    /// Returns the number of set cells
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns whether any cell is set
+    pub fn any(&self) -> bool {
+        self.words.iter().any(|&w| w != 0)
+    }
+
+    /// Iterate over the coordinates of the set cells, row by row
+    pub fn ones(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.words.iter().enumerate().flat_map(move |(i, &word)| {
+            let (y, x0) = (i / self.words_per_row, i % self.words_per_row * WORD_BITS);
+            let mut rest = word;
+            std::iter::from_fn(move || {
+                (rest != 0).then(|| {
+                    let bit = rest.trailing_zeros() as usize;
+                    rest &= rest - 1;
+                    Coord::new((x0 + bit) as i32, y as i32)
+                })
+            })
+        })
+    }
+
+    /// The 3 cells of row `y` around column `x`, as the lowest 3 bits
+    #[inline]
+    fn window(&self, x: usize, y: usize) -> u64 {
+        let row = self.row(y);
+        let i = x / WORD_BITS;
+        let previous = if i > 0 { row[i - 1] } else { 0 };
+        let next = row.get(i + 1).copied().unwrap_or(0);
+
+        // bit 0 is the last cell of the previous word, bit 65 the first cell of the next word.
+        let cells = (u128::from(row[i]) << 1)
+            | u128::from(previous >> (WORD_BITS - 1))
+            | (u128::from(next & 1) << (WORD_BITS + 1));
+        (cells >> (x % WORD_BITS)) as u64 & 0b111
+    }
+
+    /// Returns the number of set cells among the 8 neighbors of `coord`
+    pub fn neighbor_count(&self, coord: Coord) -> u32 {
+        let Some((x, y)) = self.position(coord) else {
+            return 0;
+        };
+        let rows = y.saturating_sub(1)..(y + 2).min(self.height);
+        let around: u32 = rows.map(|y| self.window(x, y).count_ones()).sum();
+        around - u32::from(self.bit(x, y))
+    }
+
+    /// Shifts a row one cell to the right, i.e. every cell gets the value of its left neighbor
+    #[inline]
+    fn shifted_right(row: &[u64], i: usize) -> u64 {
+        let carry = if i > 0 {
+            row[i - 1] >> (WORD_BITS - 1)
+        } else {
+            0
+        };
+        (row[i] << 1) | carry
+    }
+
+    /// Shifts a row one cell to the left, i.e. every cell gets the value of its right neighbor
+    #[inline]
+    fn shifted_left(row: &[u64], i: usize) -> u64 {
+        let carry = row.get(i + 1).map_or(0, |&next| next << (WORD_BITS - 1));
+        (row[i] >> 1) | carry
+    }
+
+    /// Returns the cells with fewer than `n` of their 8 neighbors set, whether they are set or not.
+    ///
+    /// Counts the neighbors of 64 cells at once: the 8 neighbor rows are shifted into place
+    /// and summed into a 4 bit counter per cell, stored as 4 words of bits.
+    pub fn fewer_neighbors_than(&self, n: u32) -> BitGrid {
+        // counts are at most 8, clamping keeps `n` within the 4 bits of the counters.
+        let n = n.min(9);
+        let mut result = BitGrid::new(self.width, self.height);
+        let empty = vec![0; self.words_per_row];
+
+        for y in 0..self.height {
+            let above = if y > 0 { self.row(y - 1) } else { &empty };
+            let below = if y + 1 < self.height {
+                self.row(y + 1)
+            } else {
+                &empty
+            };
+            let row = self.row(y);
+
+            for i in 0..self.words_per_row {
+                let neighbors = [
+                    Self::shifted_right(above, i),
+                    above[i],
+                    Self::shifted_left(above, i),
+                    Self::shifted_right(row, i),
+                    Self::shifted_left(row, i),
+                    Self::shifted_right(below, i),
+                    below[i],
+                    Self::shifted_left(below, i),
+                ];
+
+                // bit-sliced ripple carry adder, counts of up to 8 fit 4 bits.
+                let mut count = [0u64; 4];
+                for mut carry in neighbors {
+                    for bit in &mut count {
+                        let next = *bit & carry;
+                        *bit ^= carry;
+                        carry = next;
+                    }
+                }
+
+                // compare the counts with `n` from the highest bit down.
+                let (mut less, mut equal) = (0, !0);
+                for (b, bit) in count.iter().enumerate().rev() {
+                    if n >> b & 1 == 1 {
+                        less |= equal & !bit;
+                        equal &= bit;
+                    } else {
+                        equal &= !bit;
+                    }
+                }
+
+                result.words[y * self.words_per_row + i] = less;
+            }
+        }
+
+        result.clear_padding();
+        result
+    }
+
+    /// Unsets the bits past the width of every row
+    fn clear_padding(&mut self) {
+        let used = self.width % WORD_BITS;
+        if used == 0 {
+            return;
+        }
+        let mask = (1 << used) - 1;
+        for y in 0..self.height {
+            *self.row_mut(y).last_mut().unwrap() &= mask;
+        }
+    }
+
+    fn assert_same_size(&self, other: &BitGrid) {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "grids of different sizes"
+        );
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        BitGrid::from_grid(grid, |&cell| cell)
+    }
+}
+
+impl From<&BitGrid> for Grid<bool> {
+    fn from(grid: &BitGrid) -> Self {
+        grid.to_grid()
+    }
+}
+
+impl BitAndAssign<&BitGrid> for BitGrid {
+    fn bitand_assign(&mut self, rhs: &BitGrid) {
+        self.assert_same_size(rhs);
+        self.words
+            .iter_mut()
+            .zip(&rhs.words)
+            .for_each(|(a, b)| *a &= b);
+    }
+}
+
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitand(self, rhs: &BitGrid) -> Self::Output {
+        let mut result = self.clone();
+        result &= rhs;
+        result
+    }
+}
+
+impl BitOrAssign<&BitGrid> for BitGrid {
+    fn bitor_assign(&mut self, rhs: &BitGrid) {
+        self.assert_same_size(rhs);
+        self.words
+            .iter_mut()
+            .zip(&rhs.words)
+            .for_each(|(a, b)| *a |= b);
+    }
+}
+
+/// Unsets the cells that are set in `rhs`.
+impl SubAssign<&BitGrid> for BitGrid {
+    fn sub_assign(&mut self, rhs: &BitGrid) {
+        self.assert_same_size(rhs);
+        self.words
+            .iter_mut()
+            .zip(&rhs.words)
+            .for_each(|(a, b)| *a &= !b);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A deterministic grid of the given size with roughly 60% of the cells set.
+    fn sample_grid(width: usize, height: usize) -> Grid<bool> {
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let cells = (0..width * height)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state % 10 < 6
+            })
+            .collect();
+        Grid::new(width, height, cells)
+    }
+
+    fn naive_neighbor_count(grid: &Grid<bool>, coord: Coord) -> u32 {
+        grid.neighbors(coord).filter(|&(_, &set)| set).count() as u32
+    }
+
+    #[test]
+    fn converts_from_and_to_grid() {
+        for (width, height) in [(1, 1), (5, 3), (63, 2), (64, 2), (65, 3), (137, 4)] {
+            let grid = sample_grid(width, height);
+            let bits = BitGrid::from(&grid);
+            assert_eq!(bits.to_grid(), grid);
+            assert_eq!(
+                bits.count_ones(),
+                grid.cells().iter().filter(|&&set| set).count()
+            );
+            assert_eq!(
+                bits.ones().collect::<Vec<_>>(),
+                grid.positions(|&set| set).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn parses_grids() {
+        let is_paper = |b| match b {
+            b'@' => Some(true),
+            b'.' => Some(false),
+            _ => None,
+        };
+        let bits = BitGrid::parse("@..\n.@@\n", is_paper).unwrap();
+        assert_eq!((bits.width(), bits.height()), (3, 2));
+        assert_eq!(
+            bits.ones().collect::<Vec<_>>(),
+            vec![Coord::new(0, 0), Coord::new(1, 1), Coord::new(2, 1)]
+        );
+        assert_eq!(
+            BitGrid::parse("@.\n.x\n", is_paper),
+            Err(ParseGridError::UnexpectedByte {
+                byte: b'x',
+                coord: Coord::new(1, 1)
+            })
+        );
+        assert!(BitGrid::parse("@.\n.\n", is_paper).is_err());
+    }
+
+    #[test]
+    fn gets_and_sets_cells() {
+        let mut bits = BitGrid::new(70, 2);
+        bits.set(Coord::new(65, 1), true);
+        assert!(bits.get(Coord::new(65, 1)));
+        assert!(!bits.get(Coord::new(64, 1)));
+        assert!(!bits.get(Coord::new(-1, 0)));
+        assert!(!bits.get(Coord::new(70, 0)));
+        assert!(bits.any());
+        bits.set(Coord::new(65, 1), false);
+        assert!(!bits.any());
+    }
+
+    #[test]
+    fn counts_neighbors() {
+        for (width, height) in [(1, 1), (3, 3), (64, 3), (65, 5), (130, 7)] {
+            let grid = sample_grid(width, height);
+            let bits = BitGrid::from(&grid);
+            for (coord, _) in grid.iter() {
+                assert_eq!(
+                    bits.neighbor_count(coord),
+                    naive_neighbor_count(&grid, coord),
+                    "{width}x{height} at {coord:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn finds_cells_with_few_neighbors() {
+        for (width, height) in [(1, 1), (3, 3), (63, 4), (64, 3), (65, 5), (137, 9)] {
+            let grid = sample_grid(width, height);
+            let bits = BitGrid::from(&grid);
+            for n in [0, 1, 3, 4, 5, 8, 9, 20] {
+                let expected = grid.map(|_| false);
+                let expected = Grid::new(
+                    width,
+                    height,
+                    expected
+                        .iter()
+                        .map(|(coord, _)| naive_neighbor_count(&grid, coord) < n)
+                        .collect(),
+                );
+                assert_eq!(
+                    bits.fewer_neighbors_than(n).to_grid(),
+                    expected,
+                    "{width}x{height} with n = {n}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn combines_grids() {
+        let grid = sample_grid(70, 3);
+        let bits = BitGrid::from(&grid);
+        let mut inverse = BitGrid::from_grid(&grid, |&set| !set);
+
+        assert!(!(&bits & &inverse).any());
+        inverse |= &bits;
+        assert_eq!(inverse.count_ones(), 70 * 3);
+        inverse -= &bits;
+        assert_eq!(inverse.count_ones(), 70 * 3 - bits.count_ones());
+    }
+}
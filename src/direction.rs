@@ -0,0 +1,140 @@
+use crate::Coord;
+
+/// One of the 8 directions on a grid, in clockwise order starting at `Up`.
+/// `Up` decreases `y`, like in `Coord::up`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// All 8 directions, clockwise starting at `Up`
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// The 4 directions without the diagonal ones, clockwise starting at `Up`
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Parse an arrow like `^`, `>`, `v` or `<`
+    pub fn from_arrow(arrow: u8) -> Option<Self> {
+        match arrow {
+            b'^' => Some(Direction::Up),
+            b'>' => Some(Direction::Right),
+            b'v' | b'V' => Some(Direction::Down),
+            b'<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// Turns by `eighths` of a full turn, clockwise for positive values
+    #[inline]
+    fn turn(self, eighths: i32) -> Self {
+        Direction::ALL[(self as i32 + eighths).rem_euclid(8) as usize]
+    }
+
+    /// Return the direction turned 90° clockwise
+    #[inline]
+    pub fn turn_right(self) -> Self {
+        self.turn(2)
+    }
+
+    /// Return the direction turned 90° counter-clockwise
+    #[inline]
+    pub fn turn_left(self) -> Self {
+        self.turn(-2)
+    }
+
+    /// Return the direction turned 45° clockwise, e.g. `Up` becomes `UpRight`
+    #[inline]
+    pub fn turn_right_45(self) -> Self {
+        self.turn(1)
+    }
+
+    /// Return the direction turned 45° counter-clockwise, e.g. `Up` becomes `UpLeft`
+    #[inline]
+    pub fn turn_left_45(self) -> Self {
+        self.turn(-1)
+    }
+
+    /// Return the opposite direction
+    #[inline]
+    pub fn reverse(self) -> Self {
+        self.turn(4)
+    }
+
+    #[inline]
+    pub fn is_diagonal(self) -> bool {
+        self as u8 % 2 == 1
+    }
+
+    /// Returns the coordinate one step in this direction away from the origin
+    #[inline]
+    pub fn offset(self) -> Coord {
+        match self {
+            Direction::Up => Coord::new(0, -1),
+            Direction::UpRight => Coord::new(1, -1),
+            Direction::Right => Coord::new(1, 0),
+            Direction::DownRight => Coord::new(1, 1),
+            Direction::Down => Coord::new(0, 1),
+            Direction::DownLeft => Coord::new(-1, 1),
+            Direction::Left => Coord::new(-1, 0),
+            Direction::UpLeft => Coord::new(-1, -1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_directions() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::UpLeft.turn_right_45(), Direction::Up);
+        assert_eq!(Direction::Up.turn_left_45(), Direction::UpLeft);
+        assert_eq!(Direction::DownRight.reverse(), Direction::UpLeft);
+        for direction in Direction::ALL {
+            assert_eq!(direction.reverse().reverse(), direction);
+            assert_eq!(direction.turn_right().turn_left(), direction);
+        }
+    }
+
+    #[test]
+    fn offsets_match_coord_steps() {
+        let origin = Coord::new(0, 0);
+        assert_eq!(Direction::Up.offset(), origin.up());
+        assert_eq!(Direction::Right.offset(), origin.right());
+        assert_eq!(Direction::DownLeft.offset(), origin.down().left());
+        assert!(Direction::ORTHOGONAL.iter().all(|d| !d.is_diagonal()));
+        assert_eq!(Direction::ALL.iter().filter(|d| d.is_diagonal()).count(), 4);
+    }
+
+    #[test]
+    fn parses_arrows() {
+        assert_eq!(Direction::from_arrow(b'^'), Some(Direction::Up));
+        assert_eq!(Direction::from_arrow(b'v'), Some(Direction::Down));
+        assert_eq!(Direction::from_arrow(b'.'), None);
+    }
+}
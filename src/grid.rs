@@ -0,0 +1,391 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::Coord;
+
+/// A rectangular grid of cells, stored row by row and addressed by `Coord`.
+/// `(0, 0)` is the top left cell, `x` grows to the right and `y` grows downwards.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// An error which can be returned when parsing a grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseGridError {
+    Empty,
+    /// A row with a different length than the first row.
+    RaggedRow {
+        y: usize,
+        width: usize,
+        expected: usize,
+    },
+    /// A byte the mapping of the cells rejected.
+    UnexpectedByte {
+        byte: u8,
+        coord: Coord,
+    },
+}
+
+impl Error for ParseGridError {}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseGridError::Empty => f.write_str("grid is empty."),
+            ParseGridError::RaggedRow { y, width, expected } => write!(
+                f,
+                "row {y} has a width of {width}, expected {expected} like the first row."
+            ),
+            ParseGridError::UnexpectedByte { byte, coord } => write!(
+                f,
+                "unexpected `{}` at ({}, {}).",
+                byte.escape_ascii(),
+                coord.x(),
+                coord.y()
+            ),
+        }
+    }
+}
+
+/// Splits `input` into rows of equal width, ignoring trailing empty lines.
+pub(crate) fn parse_rows(input: &str) -> Result<(usize, Vec<&[u8]>), ParseGridError> {
+    let mut rows: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+    while rows.last().is_some_and(|row| row.is_empty()) {
+        rows.pop();
+    }
+
+    let width = rows.first().map_or(0, |row| row.len());
+    if width == 0 {
+        return Err(ParseGridError::Empty);
+    }
+
+    match rows.iter().position(|row| row.len() != width) {
+        Some(y) => Err(ParseGridError::RaggedRow {
+            y,
+            width: rows[y].len(),
+            expected: width,
+        }),
+        None => Ok((width, rows)),
+    }
+}
+
+impl<T> Grid<T> {
+    /// Create a grid from its cells, row by row.
+    /// Panics if `width` is zero or `cells` does not fit `width` and `height`.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert!(width > 0, "a grid must be at least one cell wide");
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "expected {width}x{height} cells"
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Create a grid with every cell set to `value`. Panics if `width` is zero.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Parse a grid with one row per line, mapping every byte to a cell.
+    /// Bytes mapped to `None` are reported as errors, so are rows of different lengths.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(u8) -> Option<T>,
+    ) -> Result<Self, ParseGridError> {
+        let (width, rows) = parse_rows(input)?;
+
+        let mut cells = Vec::with_capacity(width * rows.len());
+        for (y, row) in rows.iter().enumerate() {
+            for (x, &byte) in row.iter().enumerate() {
+                let value = cell(byte).ok_or(ParseGridError::UnexpectedByte {
+                    byte,
+                    coord: Coord::new(x as i32, y as i32),
+                })?;
+                cells.push(value);
+            }
+        }
+
+        Ok(Grid::new(width, rows.len(), cells))
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns all cells, row by row
+    #[inline]
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Returns whether `coord` is inside the grid
+    #[inline]
+    pub fn contains(&self, coord: Coord) -> bool {
+        self.index_of(coord).is_some()
+    }
+
+    /// Returns the index of `coord` in `cells`, or `None` if it is outside the grid
+    #[inline]
+    pub fn index_of(&self, coord: Coord) -> Option<usize> {
+        let x = usize::try_from(coord.x())
+            .ok()
+            .filter(|&x| x < self.width)?;
+        let y = usize::try_from(coord.y())
+            .ok()
+            .filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    /// Returns the coordinate of the cell at `index` in `cells`
+    #[inline]
+    pub fn coord_of(&self, index: usize) -> Coord {
+        Coord::new((index % self.width) as i32, (index / self.width) as i32)
+    }
+
+    #[inline]
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.index_of(coord).map(|index| &self.cells[index])
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.index_of(coord).map(|index| &mut self.cells[index])
+    }
+
+    /// Iterate over all cells with their coordinates, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (self.coord_of(index), cell))
+    }
+
+    /// Iterate over the rows, from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks_exact(self.width)
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Iterate over the cells of column `x`, from top to bottom. Empty if `x` is outside the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let height = if x < self.width { self.height } else { 0 };
+        (0..height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    /// Iterate over the columns, from left to right
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Iterate over the up to 8 neighbors of `coord` inside the grid, including diagonal ones
+    pub fn neighbors(&self, coord: Coord) -> impl Iterator<Item = (Coord, &T)> {
+        coord
+            .neighbors()
+            .into_iter()
+            .filter_map(|neighbor| Some((neighbor, self.get(neighbor)?)))
+    }
+
+    /// Iterate over the up to 4 neighbors of `coord` inside the grid, excluding diagonal ones
+    pub fn orthogonal_neighbors(&self, coord: Coord) -> impl Iterator<Item = (Coord, &T)> {
+        coord
+            .orthogonal_neighbors()
+            .into_iter()
+            .filter_map(|neighbor| Some((neighbor, self.get(neighbor)?)))
+    }
+
+    /// Returns the coordinate of the first cell matching `predicate`, row by row
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Coord> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|index| self.coord_of(index))
+    }
+
+    /// Iterate over the coordinates of all cells matching `predicate`, row by row
+    pub fn positions<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Coord> + 'a {
+        self.iter()
+            .filter_map(move |(coord, cell)| predicate(cell).then_some(coord))
+    }
+
+    /// Create a grid of the same size with every cell mapped by `f`
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl Grid<u8> {
+    /// Parse a grid of the raw bytes of `input`
+    pub fn from_bytes(input: &str) -> Result<Self, ParseGridError> {
+        Grid::parse(input, Some)
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &Self::Output {
+        self.get(coord).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) is outside of the {}x{} grid",
+                coord.x(),
+                coord.y(),
+                self.width,
+                self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(coord).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) is outside of the {width}x{height} grid",
+                coord.x(),
+                coord.y()
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "..@\n@@.\n.@@\n";
+
+    fn parse_example() -> Grid<bool> {
+        Grid::parse(EXAMPLE, |b| match b {
+            b'@' => Some(true),
+            b'.' => Some(false),
+            _ => None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_grid() {
+        let grid = parse_example();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.get(Coord::new(2, 0)), Some(&true));
+        assert!(!grid[Coord::new(0, 0)]);
+        assert_eq!(grid.coord_of(5), Coord::new(2, 1));
+        assert_eq!(grid.index_of(Coord::new(2, 1)), Some(5));
+    }
+
+    #[test]
+    fn reports_parse_errors() {
+        assert_eq!(
+            Grid::parse("..\n.x\n", |b| (b == b'.').then_some(())),
+            Err(ParseGridError::UnexpectedByte {
+                byte: b'x',
+                coord: Coord::new(1, 1)
+            })
+        );
+        assert_eq!(
+            Grid::from_bytes("...\n..\n"),
+            Err(ParseGridError::RaggedRow {
+                y: 1,
+                width: 2,
+                expected: 3
+            })
+        );
+        assert_eq!(Grid::from_bytes("\n"), Err(ParseGridError::Empty));
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one cell wide")]
+    fn rejects_empty_grid() {
+        Grid::<u8>::new(0, 0, vec![]);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one cell wide")]
+    fn rejects_zero_width_grid() {
+        Grid::filled(0, 3, false);
+    }
+
+    #[test]
+    fn rejects_coords_out_of_bounds() {
+        let grid = parse_example();
+        assert_eq!(grid.get(Coord::new(-1, 0)), None);
+        assert_eq!(grid.get(Coord::new(0, -1)), None);
+        assert_eq!(grid.get(Coord::new(3, 0)), None);
+        assert_eq!(grid.get(Coord::new(0, 3)), None);
+        assert!(!grid.contains(Coord::new(i32::MIN, 0)));
+    }
+
+    #[test]
+    fn iterates_neighbors() {
+        let grid = parse_example();
+        assert_eq!(grid.neighbors(Coord::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbors(Coord::new(1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbors(Coord::new(1, 1))
+                .filter(|&(_, &paper)| paper)
+                .count(),
+            4
+        );
+        assert_eq!(grid.orthogonal_neighbors(Coord::new(0, 0)).count(), 2);
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let grid = Grid::from_bytes("abc\ndef\n").unwrap();
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![b"abc", b"def"]);
+        assert_eq!(grid.row(1), Some(&b"def"[..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), b"be");
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    fn finds_positions() {
+        let grid = parse_example();
+        assert_eq!(grid.find(|&paper| paper), Some(Coord::new(2, 0)));
+        assert_eq!(
+            grid.positions(|&paper| !paper).collect::<Vec<_>>(),
+            vec![
+                Coord::new(0, 0),
+                Coord::new(1, 0),
+                Coord::new(2, 1),
+                Coord::new(0, 2)
+            ]
+        );
+        assert_eq!(
+            grid.map(|&paper| u8::from(paper))
+                .cells()
+                .iter()
+                .sum::<u8>(),
+            5
+        );
+    }
+}
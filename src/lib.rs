@@ -1,8 +1,18 @@
 use rayon::prelude::*;
-use std::cmp::{max, min};
-
+use std::{
+    cmp::{max, min},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+mod bit_grid;
+mod direction;
+mod grid;
+pub mod search;
 pub mod template;
 mod utils;
+pub use bit_grid::*;
+pub use direction::*;
+pub use grid::*;
 pub use utils::*;
 
 pub fn par_process_lines<R, F, T>(reader: R, f: F) -> Vec<T>
@@ -69,6 +79,12 @@ impl Coord {
         Coord(self.0 + 1, self.1)
     }
 
+    /// Get the 4 neighboring coordinates, excluding diagonal ones
+    #[inline]
+    pub fn orthogonal_neighbors(&self) -> [Self; 4] {
+        [self.up(), self.down(), self.left(), self.right()]
+    }
+
     /// Get all 8 neighboring coordinates
     #[inline]
     pub fn neighbors(&self) -> [Self; 8] {
@@ -83,6 +99,86 @@ impl Coord {
             self.down().right(),
         ]
     }
+
+    /// Return a new coordinate, `n` steps in `direction`
+    #[inline]
+    pub fn step(&self, direction: Direction, n: i32) -> Self {
+        *self + direction.offset() * n
+    }
+
+    /// Distance when moving along the axes only
+    #[inline]
+    pub fn manhattan_distance(&self, other: &Coord) -> u32 {
+        self.0.abs_diff(other.0) + self.1.abs_diff(other.1)
+    }
+
+    /// Distance when moving diagonally as well, i.e. the number of king moves
+    #[inline]
+    pub fn chebyshev_distance(&self, other: &Coord) -> u32 {
+        self.0.abs_diff(other.0).max(self.1.abs_diff(other.1))
+    }
+
+    /// Return a new coordinate, rotated 90° clockwise about the origin (with `y` growing downwards)
+    #[inline]
+    pub fn rotate_right(&self) -> Self {
+        Coord(-self.1, self.0)
+    }
+
+    /// Return a new coordinate, rotated 90° counter-clockwise about the origin (with `y` growing downwards)
+    #[inline]
+    pub fn rotate_left(&self) -> Self {
+        Coord(self.1, -self.0)
+    }
+}
+
+impl Add for Coord {
+    type Output = Coord;
+
+    #[inline]
+    fn add(self, rhs: Coord) -> Self::Output {
+        Coord(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl AddAssign for Coord {
+    #[inline]
+    fn add_assign(&mut self, rhs: Coord) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Coord {
+    type Output = Coord;
+
+    #[inline]
+    fn sub(self, rhs: Coord) -> Self::Output {
+        Coord(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl SubAssign for Coord {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Coord) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i32> for Coord {
+    type Output = Coord;
+
+    #[inline]
+    fn mul(self, rhs: i32) -> Self::Output {
+        Coord(self.0 * rhs, self.1 * rhs)
+    }
+}
+
+impl Neg for Coord {
+    type Output = Coord;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Coord(-self.0, -self.1)
+    }
 }
 
 impl<T: Into<i32>> From<(T, T)> for Coord {
@@ -248,4 +344,43 @@ mod tests {
         let rect = Rectangle::new(Coord::from((0, 0)), Coord::from((10, 10)));
         assert_eq!(rect.area(), 100);
     }
+
+    #[test]
+    fn coord_arithmetic() {
+        let mut coord = Coord::new(2, 3) + Coord::new(1, -1);
+        assert_eq!(coord, Coord::new(3, 2));
+        coord -= Coord::new(1, 1);
+        assert_eq!(coord, Coord::new(2, 1));
+        assert_eq!(coord * 3, Coord::new(6, 3));
+        assert_eq!(-coord, Coord::new(-2, -1));
+        assert_eq!(Coord::new(5, 5) - coord, Coord::new(3, 4));
+    }
+
+    #[test]
+    fn coord_distances() {
+        let (a, b) = (Coord::new(1, -2), Coord::new(-3, 4));
+        assert_eq!(a.manhattan_distance(&b), 10);
+        assert_eq!(a.chebyshev_distance(&b), 6);
+        assert_eq!(a.manhattan_distance(&a), 0);
+    }
+
+    #[test]
+    fn coord_steps_and_rotations() {
+        let origin = Coord::new(0, 0);
+        assert_eq!(origin.step(Direction::Right, 3), Coord::new(3, 0));
+        assert_eq!(origin.step(Direction::UpLeft, 2), Coord::new(-2, -2));
+        assert_eq!(origin.step(Direction::Down, -1), origin.up());
+
+        let coord = Coord::new(2, -1);
+        assert_eq!(coord.rotate_right(), Coord::new(1, 2));
+        assert_eq!(coord.rotate_left(), Coord::new(-1, -2));
+        assert_eq!(coord.rotate_right().rotate_left(), coord);
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.offset().rotate_right(),
+                direction.turn_right().offset()
+            );
+        }
+        assert_eq!(origin.orthogonal_neighbors().len(), 4);
+    }
 }
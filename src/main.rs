@@ -1,144 +1,215 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
-use args::{AppArguments, parse};
+use std::io;
+
+use advent_of_code::template::commands::{
+    all, download, examples, leaderboard, login, new_year, read, scaffold, solve, status, time,
+    whoami,
+};
+use advent_of_code::template::{PuzzleId, config::config};
+use args::{AppArguments, Command};
+use clap::{CommandFactory, Parser, error::ErrorKind};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::commands::today;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{
+        config::config, runner::RunOptions, selection::DaySelection, templates::DEFAULT_TEMPLATE,
+    };
+    use clap::{Parser, Subcommand};
+    use clap_complete::Shell;
+
+    #[derive(Parser)]
+    #[command(
+        name = "advent_of_code",
+        about = "Scaffold, run and benchmark advent of code solutions."
+    )]
+    pub struct AppArguments {
+        /// Event to work on. Defaults to `year` in aoc.toml.
+        #[arg(long, global = true, value_parser = parse_year)]
+        pub year: Option<u16>,
+
+        #[command(subcommand)]
+        pub command: Command,
+    }
 
-    pub enum AppArguments {
+    #[derive(Subcommand)]
+    pub enum Command {
+        /// Download the input and puzzle description of a day via aoc-cli.
         Download {
-            day: Day,
+            /// Day of the event, e.g. `8`.
+            day: u8,
         },
+        /// Show the puzzle description of a day.
         Read {
-            day: Day,
+            /// Day of the event, e.g. `8`.
+            day: u8,
         },
+        /// Extract the examples and their answers from a downloaded puzzle description.
+        Examples {
+            /// Day of the event, e.g. `8`.
+            day: u8,
+            /// Write the files without asking for confirmation.
+            #[arg(long)]
+            yes: bool,
+        },
+        /// Create the solution, input and example files of a day.
         Scaffold {
-            day: Day,
+            /// Day of the event, e.g. `8`.
+            day: u8,
+            /// Download the input and puzzle description afterwards, keeping no files if that fails.
+            #[arg(long)]
             download: bool,
+            /// Replace an existing solution file.
+            #[arg(long)]
             overwrite: bool,
+            /// Module template to start from, e.g. `grid`.
+            #[arg(long, value_name = "NAME", default_value = DEFAULT_TEMPLATE)]
+            template: String,
+            /// List the files that would be created or kept without writing them.
+            #[arg(long, conflicts_with = "download")]
+            dry_run: bool,
+            /// Print the rendered solution module and the template file it came from without writing anything.
+            #[arg(long, conflicts_with_all = ["download", "dry_run", "overwrite"])]
+            print_template: bool,
         },
+        /// Run the solution of a day.
         Solve {
-            day: Day,
+            /// Day of the event, e.g. `8`.
+            day: u8,
+            /// Build the solution with optimizations.
+            #[arg(long)]
             release: bool,
+            /// Profile heap allocations with dhat.
+            #[arg(long, conflicts_with = "release")]
             dhat: bool,
-            submit: Option<u8>,
+            /// Re-run the solution whenever its source, the library or its data files change.
+            #[arg(long, conflicts_with_all = ["dhat", "submit"])]
+            watch: bool,
+            #[command(flatten)]
+            options: RunOptions,
         },
+        /// Run the solutions of all or selected days.
         All {
+            #[command(flatten)]
+            selection: DaySelection,
+            /// Build the solutions with optimizations.
+            #[arg(long)]
             release: bool,
         },
+        /// Benchmark solutions. Without a selection, benches days that are not fully benched.
         Time {
+            #[command(flatten)]
+            selection: DaySelection,
+            /// Bench all days, including fully benched ones.
+            #[arg(long, conflicts_with_all = ["days", "unsolved", "unbenched", "changed_since", "slowest"])]
             all: bool,
-            day: Option<Day>,
+            /// Store the timings and update the readme.
+            #[arg(long)]
             store: bool,
         },
+        /// Show the lifecycle of every day, from scaffolding to benching.
+        Status {
+            #[command(flatten)]
+            selection: DaySelection,
+            /// Print the status as JSON.
+            #[arg(long)]
+            json: bool,
+            /// Skip running the example tests of every solution.
+            #[arg(long)]
+            no_tests: bool,
+        },
+        /// Validate and store an adventofcode.com session token, read from stdin or a hidden prompt.
+        Login,
+        /// Show the user of the stored session token.
+        Whoami,
+        /// Show a private leaderboard.
+        Leaderboard {
+            /// Read a saved leaderboard JSON instead of fetching it.
+            #[arg(long)]
+            file: Option<String>,
+            /// Leaderboard to fetch. Defaults to `leaderboard.id` in aoc.toml.
+            #[arg(long)]
+            id: Option<u32>,
+        },
+        /// Archive the tables of the configured year and start the next event.
+        NewYear {
+            /// Write the files without asking for confirmation.
+            #[arg(long)]
+            yes: bool,
+        },
+        /// Print a shell completion script to stdout.
+        Completions { shell: Shell },
+        /// Scaffold, download and read the puzzle of today. `--year` must be the year of that puzzle.
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            /// Count down to the next puzzle unlock and start the moment it unlocks.
+            #[arg(long)]
+            wait: bool,
+        },
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
-        let mut args = pico_args::Arguments::from_env();
-
-        let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-            },
-            Some("time") => {
-                let all = args.contains("--all");
-                let store = args.contains("--store");
-
-                AppArguments::Time {
-                    all,
-                    day: args.opt_free_from_str()?,
-                    store,
-                }
-            }
-            Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
-            },
-            Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
-            },
-            Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
-                download: args.contains("--download"),
-                overwrite: args.contains("--overwrite"),
-            },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
-            },
-            #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
-            Some(x) => {
-                eprintln!("Unknown command: {x}");
-                process::exit(1);
-            }
-            None => {
-                eprintln!("No command specified.");
-                process::exit(1);
-            }
-        };
-
-        let remaining = args.finish();
-        if !remaining.is_empty() {
-            eprintln!("Warning: unknown argument(s): {remaining:?}.");
-        }
-
-        Ok(app_args)
+    fn parse_year(s: &str) -> Result<u16, String> {
+        config().parse_year(s).map_err(|e| e.to_string())
     }
 }
 
+/// Resolves a day argument against the selected year, exits with a usage error if it is out of range.
+fn puzzle(year: u16, day: u8) -> PuzzleId {
+    PuzzleId::parse_day(&day.to_string(), year).unwrap_or_else(|e| {
+        AppArguments::command()
+            .error(ErrorKind::InvalidValue, format!("invalid day `{day}`: {e}"))
+            .exit()
+    })
+}
+
 fn main() {
-    match parse() {
-        Err(err) => {
-            eprintln!("Error: {err}");
-            std::process::exit(1);
+    let args = AppArguments::parse();
+    let year = args.year.unwrap_or(config().year);
+
+    match args.command {
+        Command::All { selection, release } => all::handle(year, &selection, release),
+        Command::Time {
+            selection,
+            all,
+            store,
+        } => time::handle(year, &selection, all, store),
+        Command::Download { day } => download::handle(puzzle(year, day)),
+        Command::Read { day } => read::handle(puzzle(year, day)),
+        Command::Examples { day, yes } => examples::handle(puzzle(year, day), yes),
+        Command::Scaffold {
+            day,
+            download,
+            overwrite,
+            template,
+            dry_run,
+            print_template,
+        } => {
+            let id = puzzle(year, day);
+            let download = download.then_some(download::download as scaffold::Download);
+            scaffold::handle(id, overwrite, &template, download, dry_run, print_template);
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold {
-                day,
-                download,
-                overwrite,
-            } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
-                }
-            }
-            AppArguments::Solve {
-                day,
-                release,
-                dhat,
-                submit,
-            } => solve::handle(day, release, dhat, submit),
-            #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
-            }
-        },
+        Command::Solve {
+            day,
+            release,
+            dhat,
+            watch,
+            options,
+        } => solve::handle(puzzle(year, day), release, dhat, watch, options),
+        Command::Status {
+            selection,
+            json,
+            no_tests,
+        } => status::handle(year, &selection, json, !no_tests),
+        Command::Login => login::handle(),
+        Command::Whoami => whoami::handle(),
+        Command::Leaderboard { file, id } => leaderboard::handle(year, file, id),
+        Command::NewYear { yes } => new_year::handle(year, yes),
+        Command::Completions { shell } => {
+            let mut command = AppArguments::command();
+            let name = command.get_name().to_string();
+            clap_complete::generate(shell, &mut command, name, &mut io::stdout());
+        }
+        #[cfg(feature = "today")]
+        Command::Today { wait } => today::handle(args.year, wait),
     };
 }
@@ -0,0 +1,420 @@
+//! Breadth-first search, Dijkstra and A* over any neighbor function.
+//!
+//! Nodes can be anything hashable, e.g. a `Coord` of a `Grid` or a `(Coord, Direction)` state.
+//! Every search accepts several start nodes, which all start at a cost of zero.
+//!
+//! ```
+//! use advent_of_code::{Coord, Grid, search};
+//!
+//! let grid = Grid::from_bytes("..#\n#..\n...").unwrap();
+//! let path = search::bfs(
+//!     [Coord::new(0, 0)],
+//!     |&coord| {
+//!         grid.orthogonal_neighbors(coord)
+//!             .filter(|&(_, &tile)| tile != b'#')
+//!             .map(|(neighbor, _)| neighbor)
+//!     },
+//!     |&coord| coord == Coord::new(2, 2),
+//! )
+//! .unwrap();
+//! assert_eq!(path.cost, 4);
+//! ```
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use hashbrown::{HashMap, hash_map::Entry};
+
+/// Cost of a step, `Default` is used as a cost of zero.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+/// A path from one of the start nodes to a reached node, including both.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub nodes: Vec<N>,
+    pub cost: C,
+}
+
+impl<N, C> Path<N, C> {
+    /// Returns the start node the path begins at
+    pub fn start(&self) -> &N {
+        self.nodes.first().unwrap()
+    }
+
+    /// Returns the node the path ends at
+    pub fn end(&self) -> &N {
+        self.nodes.last().unwrap()
+    }
+}
+
+/// All nodes reached by a search, with their lowest cost and the node they were reached from.
+#[derive(Debug, Clone)]
+pub struct Reached<N, C> {
+    visited: HashMap<N, (Option<N>, C)>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Reached<N, C> {
+    /// Returns the lowest cost of reaching `node`, or `None` if it was not reached
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.visited.get(node).map(|&(_, cost)| cost)
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.visited.contains_key(node)
+    }
+
+    pub fn len(&self) -> usize {
+        self.visited.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.visited.is_empty()
+    }
+
+    /// Iterate over the reached nodes and their lowest costs, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (&N, C)> {
+        self.visited.iter().map(|(node, &(_, cost))| (node, cost))
+    }
+
+    /// Returns the cheapest path from one of the start nodes to `node`, or `None` if it was not reached
+    pub fn path_to(&self, node: &N) -> Option<Path<N, C>> {
+        let cost = self.cost(node)?;
+        let mut nodes = vec![node.clone()];
+        while let Some((Some(parent), _)) = self.visited.get(nodes.last().unwrap()) {
+            nodes.push(parent.clone());
+        }
+        nodes.reverse();
+        Some(Path { nodes, cost })
+    }
+}
+
+fn breadth_first<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> (Reached<N, usize>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = visited.entry(start.clone()) {
+            entry.insert((None, 0));
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return (Reached { visited }, Some(node));
+        }
+
+        let cost = visited[&node].1 + 1;
+        for next in neighbors(&node) {
+            if let Entry::Vacant(entry) = visited.entry(next.clone()) {
+                entry.insert((Some(node.clone()), cost));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    (Reached { visited }, None)
+}
+
+/// Finds a path with the fewest steps from one of `starts` to a node matching `is_goal`
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let (reached, goal) = breadth_first(starts, neighbors, is_goal);
+    reached.path_to(&goal?)
+}
+
+/// Finds the fewest steps to every node reachable from `starts`, e.g. to flood fill a region
+pub fn bfs_all<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+) -> Reached<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    breadth_first(starts, neighbors, |_| false).0
+}
+
+/// A node in the priority queue, ordered so the lowest priority is popped first.
+struct Queued<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+fn best_first<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> (Reached<N, C>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut visited: HashMap<N, (Option<N>, C)> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = visited.entry(start.clone()) {
+            entry.insert((None, C::default()));
+            queue.push(Queued {
+                priority: heuristic(&start),
+                cost: C::default(),
+                node: start,
+            });
+        }
+    }
+
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        // a cheaper way to this node was found after it was queued.
+        if visited[&node].1 < cost {
+            continue;
+        }
+
+        if is_goal(&node) {
+            return (Reached { visited }, Some(node));
+        }
+
+        for (next, step) in neighbors(&node) {
+            let next_cost = cost + step;
+            match visited.entry(next.clone()) {
+                Entry::Occupied(entry) if entry.get().1 <= next_cost => continue,
+                Entry::Occupied(mut entry) => {
+                    entry.insert((Some(node.clone()), next_cost));
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert((Some(node.clone()), next_cost));
+                }
+            }
+            queue.push(Queued {
+                priority: next_cost + heuristic(&next),
+                cost: next_cost,
+                node: next,
+            });
+        }
+    }
+
+    (Reached { visited }, None)
+}
+
+/// Finds the cheapest path from one of `starts` to a node matching `is_goal`.
+/// `neighbors` returns the nodes reachable from a node together with the cost of the step.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (reached, goal) = best_first(starts, neighbors, |_| C::default(), is_goal);
+    reached.path_to(&goal?)
+}
+
+/// Finds the lowest cost of every node reachable from `starts`
+pub fn dijkstra_all<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+) -> Reached<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    best_first(starts, neighbors, |_| C::default(), |_| false).0
+}
+
+/// Like `dijkstra`, but explores nodes closer to the goal first.
+/// `heuristic` estimates the remaining cost to the goal, e.g. `Coord::manhattan_distance`,
+/// and must never overestimate it for the path to be the cheapest.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (reached, goal) = best_first(starts, neighbors, heuristic, is_goal);
+    reached.path_to(&goal?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Coord, Direction, Grid};
+
+    const MAZE: &str = "\
+S.#.....
+.##.###.
+....#...
+.##...#E
+";
+
+    fn open_neighbors(grid: &Grid<u8>, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        grid.orthogonal_neighbors(coord)
+            .filter(|&(_, &tile)| tile != b'#')
+            .map(|(neighbor, _)| neighbor)
+    }
+
+    #[test]
+    fn finds_shortest_path_in_grid() {
+        let grid = Grid::from_bytes(MAZE).unwrap();
+        let start = grid.find(|&tile| tile == b'S').unwrap();
+        let end = grid.find(|&tile| tile == b'E').unwrap();
+
+        let path = bfs(
+            [start],
+            |&coord| open_neighbors(&grid, coord),
+            |&c| c == end,
+        )
+        .unwrap();
+        assert_eq!(path.cost, 12);
+        assert_eq!(path.nodes.len(), 13);
+        assert_eq!((*path.start(), *path.end()), (start, end));
+        assert!(
+            path.nodes
+                .windows(2)
+                .all(|step| step[0].manhattan_distance(&step[1]) == 1)
+        );
+    }
+
+    #[test]
+    fn reports_unreachable_goals() {
+        let grid = Grid::from_bytes("S#.\n.#E\n").unwrap();
+        let goal = Coord::new(2, 1);
+        assert_eq!(
+            bfs(
+                [Coord::new(0, 0)],
+                |&c| open_neighbors(&grid, c),
+                |&c| c == goal
+            ),
+            None
+        );
+
+        let reached = bfs_all([Coord::new(0, 0)], |&c| open_neighbors(&grid, c));
+        assert_eq!(reached.len(), 2);
+        assert!(!reached.contains(&goal));
+    }
+
+    #[test]
+    fn searches_from_multiple_starts() {
+        let grid = Grid::from_bytes(MAZE).unwrap();
+        let starts = [Coord::new(0, 0), Coord::new(7, 0)];
+        let reached = bfs_all(starts, |&c| open_neighbors(&grid, c));
+
+        assert_eq!(reached.cost(&Coord::new(7, 3)), Some(3));
+        assert_eq!(reached.cost(&Coord::new(0, 3)), Some(3));
+        assert_eq!(reached.cost(&Coord::new(4, 3)), Some(6));
+        assert_eq!(
+            reached.path_to(&Coord::new(4, 3)).unwrap().nodes[0],
+            starts[1]
+        );
+        assert_eq!(
+            bfs(starts, |&c| open_neighbors(&grid, c), |&c| c == starts[1])
+                .unwrap()
+                .nodes,
+            vec![starts[1]]
+        );
+    }
+
+    #[test]
+    fn prefers_cheaper_paths_over_fewer_steps() {
+        // a -> d directly costs 10, via b and c it costs 3.
+        let edges = [('a', 'b', 1), ('b', 'c', 1), ('c', 'd', 1), ('a', 'd', 10)];
+        let neighbors = |&node: &char| {
+            edges
+                .iter()
+                .filter(move |&&(from, _, _)| from == node)
+                .map(|&(_, to, cost)| (to, cost))
+        };
+
+        let path = dijkstra(['a'], neighbors, |&n| n == 'd').unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.nodes, vec!['a', 'b', 'c', 'd']);
+
+        let reached = dijkstra_all(['a'], neighbors);
+        assert_eq!(reached.cost(&'d'), Some(3));
+        assert_eq!(reached.iter().count(), 4);
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let grid = Grid::from_bytes(MAZE).unwrap();
+        let (start, end) = (Coord::new(0, 0), Coord::new(7, 3));
+
+        // turning costs extra, so the state includes the direction.
+        let neighbors = |&(coord, facing): &(Coord, Direction)| {
+            let grid = &grid;
+            Direction::ORTHOGONAL
+                .into_iter()
+                .filter_map(move |direction| {
+                    let next = coord.step(direction, 1);
+                    let cost = if direction == facing { 1 } else { 1001 };
+                    (grid.get(next).is_some_and(|&tile| tile != b'#'))
+                        .then_some(((next, direction), cost))
+                })
+        };
+
+        let expected =
+            dijkstra([(start, Direction::Right)], neighbors, |&(c, _)| c == end).unwrap();
+        let path = astar(
+            [(start, Direction::Right)],
+            neighbors,
+            |&(coord, _)| coord.manhattan_distance(&end),
+            |&(coord, _)| coord == end,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, expected.cost);
+        assert_eq!(path.end().0, end);
+    }
+}
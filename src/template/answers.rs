@@ -0,0 +1,287 @@
+use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{Part, PuzzleId, config::config};
+
+/// Expected answers for the example input of a day.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExampleAnswers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+#[must_use]
+pub fn get_path_for_answers(id: PuzzleId) -> String {
+    format!(
+        "{}/examples/{}.json",
+        config().data_dir(id.year()),
+        id.day()
+    )
+}
+
+impl ExampleAnswers {
+    /// Dehydrate answers to a JSON file next to the example.
+    pub fn store_file(&self, id: PuzzleId) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(get_path_for_answers(id))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns empty answers.
+    pub fn read_from_file(id: PuzzleId) -> Self {
+        fs::read_to_string(get_path_for_answers(id))
+            .map_err(|x| x.to_string())
+            .and_then(ExampleAnswers::try_from)
+            .unwrap_or_default()
+    }
+
+    pub fn part(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_1.as_deref(),
+            Part::Two => self.part_2.as_deref(),
+        }
+    }
+}
+
+/// Formats an answer as the expected value of an example test, e.g. `Some(40)`.
+/// Unknown answers are `None`, like the result of an unsolved part.
+/// Answers that don't fit the `Option<u64>` of the templates stay `None` with a TODO, so the test still compiles.
+pub fn to_expected_value(answer: Option<&str>) -> String {
+    match answer {
+        None => "None".into(),
+        Some(answer) if answer.parse::<u64>().is_ok() => format!("Some({answer})"),
+        Some(answer) => format!(
+            "None /* TODO: expected {:?}, change the return type to fit it */",
+            answer.replace("*/", "* /")
+        ),
+    }
+}
+
+/// Name of the example test of a part in the generated test module.
+pub fn test_name(part: Part) -> &'static str {
+    match part {
+        Part::One => "test_part_one",
+        Part::Two => "test_part_two",
+    }
+}
+
+/// Finds the expected value of the first `assert_eq!(result, …)` in `text`, as a range of `text`.
+fn expected_value_range(text: &str) -> Option<(usize, usize)> {
+    const ASSERTION: &str = "assert_eq!(result,";
+    let start = text.find(ASSERTION)? + ASSERTION.len();
+
+    let mut depth = 0;
+    for (i, c) in text[start..].char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' if depth == 0 => {
+                let value = &text[start..start + i];
+                let offset = value.len() - value.trim_start().len();
+                return Some((start + offset, start + value.trim_end().len()));
+            }
+            ')' | ']' | '}' => depth -= 1,
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Sets the expected values of the example tests in the `tests` module of a solution to the known answers.
+/// Code outside of the test module is left untouched. Returns the updated module and the updated parts.
+pub fn update_example_tests(module: &str, answers: &ExampleAnswers) -> (String, Vec<Part>) {
+    let Some(tests_start) = module.find("mod tests") else {
+        return (module.to_string(), vec![]);
+    };
+
+    let mut updated = module.to_string();
+    let mut parts = vec![];
+
+    for part in Part::ALL {
+        let Some(answer) = answers.part(part) else {
+            continue;
+        };
+
+        let signature = format!("fn {}(", test_name(part));
+        let Some(test_start) = updated[tests_start..]
+            .find(&signature)
+            .map(|i| tests_start + i)
+        else {
+            continue;
+        };
+
+        // only look at the body of this test, up to the next function.
+        let body_start = test_start + signature.len();
+        let body_end = updated[body_start..]
+            .find("fn ")
+            .map_or(updated.len(), |i| body_start + i);
+
+        let Some((start, end)) = expected_value_range(&updated[body_start..body_end]) else {
+            continue;
+        };
+
+        let expected = to_expected_value(Some(answer));
+        if updated[body_start + start..body_start + end] != expected {
+            updated.replace_range(body_start + start..body_start + end, &expected);
+            parts.push(part);
+        }
+    }
+
+    (updated, parts)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<ExampleAnswers> for JsonValue {
+    fn from(value: ExampleAnswers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "part_1".into(),
+            value.part_1.map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "part_2".into(),
+            value.part_2.map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for ExampleAnswers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let get_part = |key: &str| {
+            json.get(key)
+                .map_or(Some(None), |v| {
+                    if v.is_null() {
+                        Some(None)
+                    } else {
+                        v.get::<String>().map(|s| Some(s.clone()))
+                    }
+                })
+                .ok_or(format!("Expected answers.{key} to be null or string."))
+        };
+
+        Ok(ExampleAnswers {
+            part_1: get_part("part_1")?,
+            part_2: get_part("part_2")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{ExampleAnswers, to_expected_value, update_example_tests};
+    use crate::template::Part;
+    use tinyjson::JsonValue;
+
+    /// A scaffolded solution module, its own tests are gated by `#[cfg(test)]` like in every solution.
+    const MODULE: &str = r#"pub fn part_one(input: &str) -> Option<u64> {
+    assert_eq!(result, None);
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        assert_eq!(result, Some(u64::from(3_u8)));
+    }
+}
+"#;
+
+    #[test]
+    fn handles_json_answers() {
+        let json = r#"{ "part_1": "13", "part_2": null }"#.to_string();
+        let answers = ExampleAnswers::try_from(json).unwrap();
+        assert_eq!(answers.part_1, Some("13".to_string()));
+        assert_eq!(answers.part_2, None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        let json = r#"{ "part_1": 13 }"#.to_string();
+        ExampleAnswers::try_from(json).unwrap();
+    }
+
+    #[test]
+    fn roundtrips_answers() {
+        let answers = ExampleAnswers {
+            part_1: Some("13".into()),
+            part_2: Some("43".into()),
+        };
+        let json = JsonValue::from(answers.clone()).stringify().unwrap();
+        assert_eq!(ExampleAnswers::try_from(json).unwrap(), answers);
+    }
+
+    #[test]
+    fn formats_expected_values() {
+        assert_eq!(to_expected_value(None), "None");
+        assert_eq!(to_expected_value(Some("40")), "Some(40)");
+        assert_eq!(
+            to_expected_value(Some("-3")),
+            "None /* TODO: expected \"-3\", change the return type to fit it */"
+        );
+        assert_eq!(
+            to_expected_value(Some("abc")),
+            "None /* TODO: expected \"abc\", change the return type to fit it */"
+        );
+    }
+
+    #[test]
+    fn updates_example_tests() {
+        let answers = ExampleAnswers {
+            part_1: Some("40".into()),
+            part_2: Some("25272".into()),
+        };
+        let (updated, parts) = update_example_tests(MODULE, &answers);
+        assert_eq!(parts, vec![Part::One, Part::Two]);
+        assert!(updated.contains("assert_eq!(result, Some(40));"));
+        assert!(updated.contains("assert_eq!(result, Some(25272));"));
+        // the solution code before the test module is left untouched.
+        assert!(updated.starts_with(
+            "pub fn part_one(input: &str) -> Option<u64> {\n    assert_eq!(result, None);"
+        ));
+
+        let (again, parts) = update_example_tests(&updated, &answers);
+        assert_eq!(again, updated);
+        assert!(parts.is_empty());
+    }
+
+    #[test]
+    fn skips_unknown_answers_and_missing_tests() {
+        let answers = ExampleAnswers {
+            part_1: None,
+            part_2: Some("7".into()),
+        };
+        let (updated, parts) = update_example_tests(MODULE, &answers);
+        assert_eq!(parts, vec![Part::Two]);
+        assert!(updated.contains("assert_eq!(result, None);\n    }"));
+
+        let (updated, parts) = update_example_tests("fn main() {}", &answers);
+        assert_eq!(updated, "fn main() {}");
+        assert!(parts.is_empty());
+    }
+}
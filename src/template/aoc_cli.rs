@@ -4,13 +4,19 @@ use std::{
     process::{Command, Output, Stdio},
 };
 
-use crate::template::Day;
+use crate::template::{
+    Part, PuzzleId,
+    config::config,
+    session::{self, SessionError},
+    titles,
+};
 
 #[derive(Debug)]
 pub enum AocCommandError {
     CommandNotFound,
     CommandNotCallable,
     BadExitStatus(Output),
+    Session(SessionError),
 }
 
 impl Display for AocCommandError {
@@ -21,6 +27,7 @@ impl Display for AocCommandError {
             AocCommandError::BadExitStatus(_) => {
                 write!(f, "aoc-cli exited with a non-zero status.")
             }
+            AocCommandError::Session(e) => write!(f, "{e}"),
         }
     }
 }
@@ -33,8 +40,8 @@ pub fn check() -> Result<(), AocCommandError> {
     Ok(())
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+pub fn read(id: PuzzleId) -> Result<Output, AocCommandError> {
+    let puzzle_path = config().puzzle_path(id);
 
     let args = build_args(
         "read",
@@ -43,15 +50,15 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path,
         ],
-        day,
+        id,
     );
 
     call_aoc_cli(&args)
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
+pub fn download(id: PuzzleId) -> Result<Output, AocCommandError> {
+    let input_path = config().input_path(id);
+    let puzzle_path = config().puzzle_path(id);
 
     let args = build_args(
         "download",
@@ -62,48 +69,45 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path.to_string(),
         ],
-        day,
+        id,
     );
 
     let output = call_aoc_cli(&args)?;
     println!("---");
     println!("🎄 Successfully wrote input to \"{}\".", &input_path);
     println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    match titles::record(id) {
+        Ok(Some(title)) => println!("🎄 Stored title \"{title}\"."),
+        Ok(None) => {}
+        Err(e) => eprintln!("Failed to store the title of day {}: {e}", id.day()),
+    }
     Ok(output)
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
+pub fn submit(id: PuzzleId, part: Part, result: &str) -> Result<Output, AocCommandError> {
     // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
+    let mut args = build_args("submit", &[], id);
     args.push(part.to_string());
     args.push(result.to_string());
     call_aoc_cli(&args)
 }
 
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
-}
-
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
-}
-
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
-}
-
-fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
+fn build_args(command: &str, args: &[String], id: PuzzleId) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
-    if let Some(year) = get_year() {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
+    if let Some(session_file) = session::find_session_file() {
+        cmd_args.push("--session-file".into());
+        cmd_args.push(session_file.to_string_lossy().into());
     }
 
-    cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+    cmd_args.push("--year".into());
+    cmd_args.push(id.year().to_string());
+
+    cmd_args.append(&mut vec![
+        "--day".into(),
+        id.day().to_string(),
+        command.into(),
+    ]);
 
     cmd_args
 }
@@ -118,8 +122,14 @@ fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
         .map_err(|_| AocCommandError::CommandNotCallable)?;
 
     if output.status.success() {
-        Ok(output)
-    } else {
-        Err(AocCommandError::BadExitStatus(output))
+        return Ok(output);
+    }
+
+    // aoc-cli does not tell authentication failures apart, check the session to give a clear error.
+    match session::check() {
+        Err(e @ (SessionError::Missing | SessionError::Expired)) => {
+            Err(AocCommandError::Session(e))
+        }
+        _ => Err(AocCommandError::BadExitStatus(output)),
     }
 }
@@ -0,0 +1,146 @@
+/// Moves the readme tables of a finished event next to its data, so a new event can start with empty ones.
+use crate::template::{readme_benchmarks, stars};
+
+/// The text from the first to the last occurrence of `marker`, including both markers.
+fn section<'a>(readme: &'a str, marker: &str) -> Option<&'a str> {
+    let start = readme.find(marker)?;
+    let end = readme.rfind(marker)? + marker.len();
+    (end - start > marker.len()).then(|| &readme[start..end])
+}
+
+fn replace_section(readme: &str, marker: &str, replacement: &str) -> String {
+    match section(readme, marker) {
+        Some(table) => readme.replacen(table, replacement, 1),
+        None => readme.to_string(),
+    }
+}
+
+/// Builds the readme of the archived event in `year` from the ⭐️ progress and benchmark tables of `readme`.
+/// Links to solutions are made relative to `archive_path`, so they keep working from the archived readme.
+pub fn archive_readme(readme: &str, year: u16, archive_path: &str) -> String {
+    let mut lines = vec![format!("# 🎄 Advent of Code {year}"), String::new()];
+
+    for marker in [stars::MARKER, readme_benchmarks::MARKER] {
+        let table = section(readme, marker).map_or_else(
+            || format!("{marker}\n{marker}"),
+            |table| {
+                table.replace(
+                    "](./",
+                    &format!("]({}", readme_benchmarks::root_prefix(archive_path)),
+                )
+            },
+        );
+        lines.push(table);
+        lines.push(String::new());
+    }
+
+    lines.join("\n")
+}
+
+/// Empties the tables of `readme` and moves its title from the event in `year` to the one in `next_year`.
+pub fn reset_readme(readme: &str, year: u16, next_year: u16) -> String {
+    let mut readme = readme.replacen(
+        &format!("Advent of Code {year}"),
+        &format!("Advent of Code {next_year}"),
+        1,
+    );
+
+    for marker in [stars::MARKER, readme_benchmarks::MARKER] {
+        readme = replace_section(&readme, marker, &format!("{marker}\n{marker}"));
+    }
+
+    readme
+}
+
+/// Sets the `year` of an `aoc.toml`, keeping its comments and other keys.
+pub fn set_config_year(config: &str, year: u16) -> String {
+    let mut lines: Vec<String> = config.lines().map(ToString::to_string).collect();
+
+    // keys after the first table header belong to that table.
+    let top_level = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with('['))
+        .unwrap_or(lines.len());
+
+    let year_line = lines[..top_level].iter().position(|line| {
+        line.split_once('=')
+            .is_some_and(|(key, _)| key.trim() == "year")
+    });
+
+    match year_line {
+        Some(i) => lines[i] = format!("year = {year}"),
+        None => lines.insert(0, format!("year = {year}")),
+    }
+
+    let mut config = lines.join("\n");
+    config.push('\n');
+    config
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{archive_readme, reset_readme, set_config_year};
+    use crate::template::{readme_benchmarks, stars};
+
+    fn get_mock_readme() -> String {
+        [
+            "# 🎄 Advent of Code 2025",
+            "",
+            stars::MARKER,
+            "## 2025 Results",
+            "| [Day 1](https://adventofcode.com/2025/day/1) | ⭐ | ⭐ |",
+            stars::MARKER,
+            "",
+            readme_benchmarks::MARKER,
+            "## Benchmarks",
+            "| [Day 1](./src/bin/2025_01.rs) | `1ms` | `2ms` |",
+            readme_benchmarks::MARKER,
+            "",
+            "### Template Setup",
+        ]
+        .join("\n")
+    }
+
+    #[test]
+    fn archives_readme_tables() {
+        let archived = archive_readme(&get_mock_readme(), 2025, "data/2025/README.md");
+        assert!(archived.starts_with("# 🎄 Advent of Code 2025\n"));
+        assert!(archived.contains("| [Day 1](https://adventofcode.com/2025/day/1) | ⭐ | ⭐ |"));
+        assert!(archived.contains("| [Day 1](../../src/bin/2025_01.rs) | `1ms` | `2ms` |"));
+        assert!(!archived.contains("Template Setup"));
+        assert_eq!(archived.matches(readme_benchmarks::MARKER).count(), 2);
+    }
+
+    #[test]
+    fn archives_missing_tables_as_empty() {
+        let archived = archive_readme("# readme", 2025, "data/2025/README.md");
+        assert_eq!(archived.matches(stars::MARKER).count(), 2);
+        assert_eq!(archived.matches(readme_benchmarks::MARKER).count(), 2);
+    }
+
+    #[test]
+    fn resets_readme_tables() {
+        let reset = reset_readme(&get_mock_readme(), 2025, 2026);
+        assert!(reset.starts_with("# 🎄 Advent of Code 2026\n"));
+        assert!(reset.contains(&format!("{0}\n{0}", stars::MARKER)));
+        assert!(reset.contains(&format!("{0}\n{0}", readme_benchmarks::MARKER)));
+        assert!(!reset.contains("⭐ |"));
+        assert!(!reset.contains("2025_01.rs"));
+        assert!(reset.ends_with("### Template Setup"));
+    }
+
+    #[test]
+    fn sets_config_year() {
+        assert_eq!(
+            set_config_year("# comment\nyear = 2025\n\n[paths]\ndata = \"data\"\n", 2026),
+            "# comment\nyear = 2026\n\n[paths]\ndata = \"data\"\n"
+        );
+        assert_eq!(
+            set_config_year("[paths]\ndata = \"data\"", 2026),
+            "year = 2026\n[paths]\ndata = \"data\"\n"
+        );
+        assert_eq!(set_config_year("", 2026), "year = 2026\n");
+    }
+}
@@ -1,5 +1,25 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::process;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::{run_multi::run_parallel, selection::DaySelection};
+
+pub fn handle(year: u16, selection: &DaySelection, is_release: bool) {
+    let days_to_run = match selection.resolve(year) {
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("Invalid day selection: {e}");
+            process::exit(1);
+        }
+    };
+
+    if days_to_run.is_empty() {
+        println!("No days match the selection.");
+        return;
+    }
+
+    let summary = run_parallel(year, &days_to_run, is_release);
+    summary.print();
+
+    if summary.has_failures() {
+        process::exit(1);
+    }
 }
@@ -1,16 +1,22 @@
-use crate::template::{Day, aoc_cli};
+use crate::template::{PuzzleId, aoc_cli};
 use std::process;
 
-pub fn handle(day: Day) {
+/// Downloads the input and puzzle description of a day via aoc-cli.
+pub fn download(id: PuzzleId) -> Result<(), String> {
     if aoc_cli::check().is_err() {
-        eprintln!(
+        return Err(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+                .into(),
         );
-        process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    aoc_cli::download(id).map_err(|e| format!("failed to call aoc-cli: {e}"))?;
+    Ok(())
+}
+
+pub fn handle(id: PuzzleId) {
+    if let Err(e) = download(id) {
+        eprintln!("{e}");
         process::exit(1);
-    };
+    }
 }
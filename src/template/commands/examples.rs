@@ -0,0 +1,143 @@
+use std::{fs, process};
+
+use crate::template::{
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Part, PuzzleId,
+    answers::{
+        ExampleAnswers, get_path_for_answers, test_name, to_expected_value, update_example_tests,
+    },
+    config::config,
+    confirm,
+    puzzle::extract_examples,
+};
+
+struct ProposedFile {
+    path: String,
+    contents: String,
+}
+
+fn describe_change(path: &str, contents: &str) -> &'static str {
+    match fs::read_to_string(path) {
+        Ok(existing) if existing == contents => "unchanged",
+        Ok(existing) if existing.trim().is_empty() => "fill empty file",
+        Ok(_) => "overwrite",
+        Err(_) => "create",
+    }
+}
+
+pub fn handle(id: PuzzleId, yes: bool) {
+    let config = config();
+    let puzzle_path = config.puzzle_path(id);
+
+    let Ok(puzzle) = fs::read_to_string(&puzzle_path) else {
+        eprintln!(
+            "Puzzle description \"{puzzle_path}\" not found. Run `cargo download {} --year {}` first.",
+            id.day(),
+            id.year()
+        );
+        process::exit(1);
+    };
+
+    let examples = extract_examples(&puzzle);
+
+    let Some(input_1) = examples.first().and_then(|e| e.input.clone()) else {
+        eprintln!("Could not find an example in \"{puzzle_path}\".");
+        process::exit(1);
+    };
+
+    let mut files = vec![ProposedFile {
+        path: config.example_path(id),
+        contents: format!("{input_1}\n"),
+    }];
+
+    // only write a part-specific example when part two brings its own.
+    if let Some(input_2) = examples.get(1).and_then(|e| e.input.clone())
+        && input_2 != input_1
+    {
+        files.push(ProposedFile {
+            path: config.part_example_path(id, Part::Two),
+            contents: format!("{input_2}\n"),
+        });
+    }
+
+    let answers = ExampleAnswers {
+        part_1: examples.first().and_then(|e| e.answer.clone()),
+        part_2: examples.get(1).and_then(|e| e.answer.clone()),
+    };
+
+    for file in &files {
+        println!(
+            "{ANSI_BOLD}{}{ANSI_RESET} {ANSI_ITALIC}({}){ANSI_RESET}",
+            file.path,
+            describe_change(&file.path, &file.contents)
+        );
+        for line in file.contents.lines() {
+            println!("    {line}");
+        }
+        println!();
+    }
+
+    println!(
+        "{ANSI_BOLD}{}{ANSI_RESET} {ANSI_ITALIC}({}){ANSI_RESET}",
+        get_path_for_answers(id),
+        if ExampleAnswers::read_from_file(id) == answers {
+            "unchanged"
+        } else {
+            "update"
+        }
+    );
+    println!(
+        "    Part 1: {}",
+        answers.part_1.as_deref().unwrap_or("unknown")
+    );
+    println!(
+        "    Part 2: {}",
+        answers.part_2.as_deref().unwrap_or("unknown")
+    );
+    println!();
+
+    // fill the expected answers into the tests of an existing solution.
+    let module_path = config.bin_path(id);
+    let module_update = fs::read_to_string(&module_path)
+        .ok()
+        .map(|module| update_example_tests(&module, &answers))
+        .filter(|(_, parts)| !parts.is_empty());
+
+    if let Some((_, parts)) = &module_update {
+        println!("{ANSI_BOLD}{module_path}{ANSI_RESET} {ANSI_ITALIC}(update tests){ANSI_RESET}");
+        for part in parts {
+            println!(
+                "    {}: assert_eq!(result, {});",
+                test_name(*part),
+                to_expected_value(answers.part(*part))
+            );
+        }
+        println!();
+    }
+
+    if !yes && !confirm("Write these files?") {
+        println!("Aborted, nothing was written.");
+        return;
+    }
+
+    for file in &files {
+        if let Err(e) = fs::write(&file.path, &file.contents) {
+            eprintln!("Failed to write example file \"{}\": {e}", file.path);
+            process::exit(1);
+        }
+        println!("Wrote example file \"{}\"", file.path);
+    }
+
+    if let Err(e) = answers.store_file(id) {
+        eprintln!("Failed to write expected answers: {e}");
+        process::exit(1);
+    }
+    println!("Wrote expected answers \"{}\"", get_path_for_answers(id));
+
+    if let Some((module, _)) = module_update {
+        if let Err(e) = fs::write(&module_path, module) {
+            eprintln!("Failed to update the tests of \"{module_path}\": {e}");
+            process::exit(1);
+        }
+        println!("Updated the tests of \"{module_path}\"");
+    }
+}
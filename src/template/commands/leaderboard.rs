@@ -0,0 +1,111 @@
+use std::{
+    fs,
+    path::Path,
+    process,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+    config::config,
+    leaderboard::{Leaderboard, format_delta, format_timestamp},
+    session,
+};
+
+/// The website asks to not request leaderboards more often than every 15 minutes.
+const CACHE_DURATION: Duration = Duration::from_secs(15 * 60);
+
+fn get_cache_path(year: u16, id: u32) -> String {
+    format!("{}/leaderboard-{id}.json", config().data_dir(year))
+}
+
+fn is_fresh(path: &str) -> bool {
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age < CACHE_DURATION)
+}
+
+fn fetch(year: u16, id: u32) -> Result<String, String> {
+    let cache_path = get_cache_path(year, id);
+
+    if is_fresh(&cache_path) {
+        println!("{ANSI_ITALIC}Using leaderboard cached at \"{cache_path}\".{ANSI_RESET}\n");
+        return fs::read_to_string(&cache_path).map_err(|e| e.to_string());
+    }
+
+    let url = format!("https://adventofcode.com/{year}/leaderboard/private/view/{id}.json");
+    let json = session::fetch(&url).map_err(|e| e.to_string())?;
+
+    if Path::new(&config().data_dir(year)).is_dir()
+        && let Err(e) = fs::write(&cache_path, &json)
+    {
+        eprintln!("Warning: could not cache leaderboard: {e}");
+    }
+
+    Ok(json)
+}
+
+fn print_leaderboard(leaderboard: &Leaderboard) {
+    println!("{ANSI_BOLD}Leaderboard {}{ANSI_RESET}", leaderboard.event);
+    println!();
+    println!("  # | Score | Stars | Name");
+    for (i, member) in leaderboard.members.iter().enumerate() {
+        println!(
+            "{:>3} | {:>5} | {:>5} | {}",
+            i + 1,
+            member.local_score,
+            member.stars,
+            member.name
+        );
+    }
+
+    for member in leaderboard.members.iter().filter(|m| !m.days.is_empty()) {
+        println!();
+        println!(
+            "{ANSI_BOLD}{}{ANSI_RESET} {ANSI_ITALIC}({} ⭐, {} points){ANSI_RESET}",
+            member.name, member.stars, member.local_score
+        );
+        println!("  Day | Part 1         | Part 2         | Delta");
+
+        for (day, completion) in &member.days {
+            let format_part = |ts: Option<i64>| {
+                ts.map_or_else(
+                    || "-".to_string(),
+                    |ts| format_timestamp(ts, config().server_utc_offset),
+                )
+            };
+
+            println!(
+                "  {day:>3} | {:<14} | {:<14} | {}",
+                format_part(completion.part_1),
+                format_part(completion.part_2),
+                completion
+                    .delta()
+                    .map_or_else(|| "-".to_string(), format_delta)
+            );
+        }
+    }
+}
+
+pub fn handle(year: u16, file: Option<String>, id: Option<u32>) {
+    let json = match (file, id.or(config().leaderboard_id)) {
+        (Some(file), _) => fs::read_to_string(&file).map_err(|e| format!("{file}: {e}")),
+        (None, Some(id)) => fetch(year, id),
+        (None, None) => Err(
+            "either pass a saved leaderboard with `--file <path>` or a leaderboard `--id <id>`."
+                .into(),
+        ),
+    };
+
+    let leaderboard = match json.and_then(Leaderboard::try_from) {
+        Ok(leaderboard) => leaderboard,
+        Err(e) => {
+            eprintln!("Failed to load leaderboard: {e}");
+            process::exit(1);
+        }
+    };
+
+    print_leaderboard(&leaderboard);
+}
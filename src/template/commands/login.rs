@@ -0,0 +1,103 @@
+use std::{
+    io::{self, IsTerminal, Write},
+    process::{self, Command, Stdio},
+};
+
+use crate::template::session::{self, SessionError};
+
+/// Normalizes a pasted token, returns `None` if nothing but a `session=` prefix was given.
+fn parse_token(input: &str) -> Option<String> {
+    let token = session::normalize_token(input);
+    (!token.is_empty()).then_some(token)
+}
+
+/// Turns the echo of the terminal on or off via `stty`, returns whether that worked.
+fn set_echo(echo: bool) -> bool {
+    Command::new("stty")
+        .arg(if echo { "echo" } else { "-echo" })
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// Reads the token from stdin, prompting for it without echo if stdin is a terminal.
+/// The token is never taken from the command line, where it would end up in the shell history.
+fn read_token() -> io::Result<String> {
+    let stdin = io::stdin();
+    let mut token = String::new();
+
+    if !stdin.is_terminal() {
+        stdin.read_line(&mut token)?;
+        return Ok(token);
+    }
+
+    print!("Paste the value of your adventofcode.com `session` cookie (hidden): ");
+    io::stdout().flush()?;
+
+    let hidden = set_echo(false);
+    let result = stdin.read_line(&mut token);
+    if hidden {
+        set_echo(true);
+        // the newline of the input was not echoed either.
+        println!();
+    }
+
+    result.map(|_| token)
+}
+
+pub fn handle() {
+    let input = match read_token() {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read session token: {e}");
+            process::exit(1);
+        }
+    };
+
+    let Some(token) = parse_token(&input) else {
+        eprintln!("No session token given.");
+        process::exit(1);
+    };
+
+    let user = match session::whoami(&token) {
+        Ok(user) => Some(user),
+        Err(e @ SessionError::Expired) => {
+            eprintln!("Invalid session token: {e}");
+            process::exit(1);
+        }
+        // still store the token when the website could not be reached.
+        Err(e) => {
+            eprintln!("Warning: could not validate session token: {e}");
+            None
+        }
+    };
+
+    match session::store(&token) {
+        Ok(path) => {
+            if let Some(user) = user {
+                println!("🎄 Logged in as {user}.");
+            }
+            println!("🎄 Stored session token in \"{}\".", path.display());
+        }
+        Err(e) => {
+            eprintln!("Failed to store session token: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::parse_token;
+
+    #[test]
+    fn parses_pasted_tokens() {
+        assert_eq!(parse_token("session=53616c74\n"), Some("53616c74".into()));
+        assert_eq!(parse_token(" 53616c74 "), Some("53616c74".into()));
+        assert_eq!(parse_token("session="), None);
+        assert_eq!(parse_token("\n"), None);
+    }
+}
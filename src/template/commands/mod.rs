@@ -1,6 +1,14 @@
 pub mod all;
 pub mod download;
+pub mod examples;
+pub mod leaderboard;
+pub mod login;
+pub mod new_year;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod status;
 pub mod time;
+#[cfg(feature = "today")]
+pub mod today;
+pub mod whoami;
@@ -0,0 +1,92 @@
+use std::{fs, path::Path, process};
+
+use crate::template::{
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+    archive::{archive_readme, reset_readme, set_config_year},
+    config::{CONFIG_FILE_PATH, config},
+    confirm,
+    transaction::Transaction,
+};
+
+pub fn handle(year: u16, yes: bool) {
+    let config = config();
+
+    if year != config.year {
+        eprintln!(
+            "Only the configured year {} can be archived, got {year}.",
+            config.year
+        );
+        process::exit(1);
+    }
+
+    let next_year = year + 1;
+    let readme_path = config.readme_path(year);
+    let archive_path = format!("{}/README.md", config.data_dir(year));
+
+    if Path::new(&archive_path).exists() {
+        eprintln!("\"{archive_path}\" already exists, {year} seems to be archived already.");
+        process::exit(1);
+    }
+
+    let readme = match fs::read_to_string(&readme_path) {
+        Ok(readme) => readme,
+        Err(e) => {
+            eprintln!("Failed to read \"{readme_path}\": {e}");
+            process::exit(1);
+        }
+    };
+    let aoc_toml = fs::read_to_string(CONFIG_FILE_PATH).unwrap_or_default();
+
+    let files = [
+        (
+            archive_path.as_str(),
+            archive_readme(&readme, year, &archive_path),
+            format!("⭐️ progress and benchmarks of {year}"),
+        ),
+        (
+            readme_path.as_str(),
+            reset_readme(&readme, year, next_year),
+            format!("empty tables for {next_year}"),
+        ),
+        (
+            CONFIG_FILE_PATH,
+            set_config_year(&aoc_toml, next_year),
+            format!("`year = {next_year}`"),
+        ),
+    ];
+
+    println!(
+        "Solutions in \"{}\" and data in \"{}\" are already scoped to {year} and stay where they are.",
+        config.paths.bin,
+        config.data_dir(year)
+    );
+    for (path, _, description) in &files {
+        println!("{ANSI_BOLD}{path}{ANSI_RESET} {ANSI_ITALIC}({description}){ANSI_RESET}");
+    }
+    println!();
+
+    if !yes && !confirm(&format!("Archive {year} and start {next_year}?")) {
+        println!("Aborted, nothing was written.");
+        return;
+    }
+
+    // either all files are written or none are, so a failure does not leave a half started year.
+    let mut transaction = Transaction::default();
+    for (path, contents, _) in &files {
+        if let Err(e) = transaction.write(path, contents) {
+            transaction.rollback();
+            eprintln!("Failed to write \"{path}\", no files were changed: {e}");
+            process::exit(1);
+        }
+    }
+
+    for (path, _, _) in &files {
+        println!("Wrote \"{path}\"");
+    }
+
+    println!("---");
+    println!(
+        "🎄 Archived {year}, run `cargo time --year {year} --store` to update its benchmarks."
+    );
+    println!("🎄 Happy {next_year}! Run `cargo today` once the first puzzle unlocks.");
+}
@@ -1,8 +1,25 @@
-use std::process;
+use std::{env, fs, process};
 
-use crate::template::{Day, aoc_cli};
+use crate::template::{PuzzleId, aoc_cli, config::config, puzzle};
+
+/// Width that saved puzzles are wrapped to, unless the terminal is narrower.
+const MAX_WIDTH: usize = 100;
+
+fn terminal_width() -> usize {
+    env::var("COLUMNS")
+        .ok()
+        .and_then(|x| x.parse::<usize>().ok())
+        .unwrap_or(80)
+        .clamp(40, MAX_WIDTH)
+}
+
+pub fn handle(id: PuzzleId) {
+    // prefer the saved puzzle description, only go to the network if it is missing.
+    if let Ok(markdown) = fs::read_to_string(config().puzzle_path(id)) {
+        println!("{}", puzzle::render(&markdown, terminal_width()));
+        return;
+    }
 
-pub fn handle(day: Day) {
     if aoc_cli::check().is_err() {
         eprintln!(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
@@ -10,7 +27,7 @@ pub fn handle(day: Day) {
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::read(day) {
+    if let Err(e) = aoc_cli::read(id) {
         eprintln!("failed to call aoc-cli: {e}");
         process::exit(1);
     };
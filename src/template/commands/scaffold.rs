@@ -1,79 +1,342 @@
-use std::{
-    fs::{File, OpenOptions},
-    io::Write,
-    process,
-};
+use std::{fmt::Display, fs, path::Path, process};
 
-use crate::template::Day;
+use crate::template::{
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Part, PuzzleId,
+    answers::ExampleAnswers,
+    config::config,
+    templates::{self, Placeholders, Template},
+    titles,
+    transaction::Transaction,
+};
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+/// What scaffolding does to a single file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Change {
+    Create,
+    Overwrite,
+    /// Existing data files are never replaced, they may hold a downloaded input or example.
+    Keep,
+}
 
-fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
-    let mut file = OpenOptions::new();
-    if overwrite {
-        file.create(true);
-    } else {
-        file.create_new(true);
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Change::Create => "create",
+            Change::Overwrite => "overwrite",
+            Change::Keep => "keep",
+        })
     }
-    file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+struct PlannedFile {
+    path: String,
+    /// e.g. `module file`.
+    kind: &'static str,
+    contents: String,
+    change: Change,
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+impl PlannedFile {
+    fn data(path: String, kind: &'static str) -> Self {
+        let change = if Path::new(&path).exists() {
+            Change::Keep
+        } else {
+            Change::Create
+        };
+        PlannedFile {
+            path,
+            kind,
+            contents: String::new(),
+            change,
+        }
+    }
+
+    fn describe(&self) -> String {
+        match (self.change, fs::metadata(&self.path)) {
+            (Change::Keep, Ok(metadata)) if metadata.len() > 0 => {
+                format!("keep existing, {} bytes", metadata.len())
+            }
+            (Change::Keep, _) => "keep existing, empty".into(),
+            (Change::Create, _) if self.contents.is_empty() => "create empty".into(),
+            (change, _) => change.to_string(),
+        }
+    }
+}
 
-    let mut file = match safe_create_file(&module_path, overwrite) {
-        Ok(file) => file,
+fn load_template(name: &str) -> Template {
+    match templates::load(name) {
+        Ok(template) => template,
         Err(e) => {
-            eprintln!("Failed to create module file: {e}");
+            eprintln!("Invalid template: {e}");
             process::exit(1);
         }
-    };
+    }
+}
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
+fn render_module(id: PuzzleId, template: &Template) -> String {
+    // the title is known once the puzzle was downloaded, `download_into` renders the module again afterwards.
+    let title = titles::title(id);
+
+    // answers are known if `cargo examples` ran before scaffolding.
+    let answers = ExampleAnswers::read_from_file(id);
+
+    let placeholders = Placeholders::new(id, title, &answers, templates::author());
+    match templates::render(&template.contents, &placeholders) {
+        Ok(module) => module,
         Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
+            eprintln!(
+                "Invalid template `{}` from {}: {e}",
+                template.name, template.source
+            );
             process::exit(1);
         }
     }
+}
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
+/// Lists the files of a scaffolded day and what happens to each of them.
+fn plan(id: PuzzleId, overwrite: bool, template: &Template) -> Vec<PlannedFile> {
+    let config = config();
+    let module_path = config.bin_path(id);
+
+    let module_change = if !Path::new(&module_path).exists() {
+        Change::Create
+    } else if overwrite {
+        Change::Overwrite
+    } else {
+        eprintln!(
+            "Module file \"{module_path}\" already exists. Use `--overwrite` to replace it, data files are kept either way."
+        );
+        process::exit(1);
+    };
+
+    // the module comes first, `download_into` relies on it.
+    let mut files = vec![
+        PlannedFile {
+            path: module_path,
+            kind: "module file",
+            contents: render_module(id, template),
+            change: module_change,
+        },
+        PlannedFile::data(config.input_path(id), "input file"),
+    ];
+
+    // parts with an example of their own, e.g. `08-2.txt`, are tested against it instead of the shared example.
+    let part_examples: Vec<String> = Part::ALL
+        .into_iter()
+        .map(|part| config.part_example_path(id, part))
+        .filter(|path| Path::new(path).exists())
+        .collect();
+
+    for path in &part_examples {
+        files.push(PlannedFile::data(path.clone(), "example file"));
+    }
+
+    if part_examples.len() < Part::ALL.len() {
+        files.push(PlannedFile::data(config.example_path(id), "example file"));
+    }
+
+    files
+}
+
+/// Writes the planned files, undoing all changes if one of them fails.
+fn apply(id: PuzzleId, files: &[PlannedFile]) -> std::io::Result<Transaction> {
+    let mut transaction = Transaction::default();
+
+    // `download` expects the puzzle directory of the year to exist.
+    let puzzle_path = config().puzzle_path(id);
+    let result = Path::new(&puzzle_path)
+        .parent()
+        .map_or(Ok(()), |dir| transaction.create_dir_all(dir))
+        .and_then(|()| {
+            files
+                .iter()
+                .filter(|file| file.change != Change::Keep)
+                .try_for_each(|file| transaction.write(&file.path, &file.contents))
+        });
+
+    match result {
+        Ok(()) => Ok(transaction),
         Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+            transaction.rollback();
+            Err(e)
+        }
+    }
+}
+
+/// Downloads the input and puzzle description of a day, e.g. `download::download`.
+pub type Download = fn(PuzzleId) -> Result<(), String>;
+
+/// Runs `download` after scaffolding, then renders the module again with the title the puzzle description brought.
+/// Undoes the scaffolding if either fails.
+fn download_into(
+    mut transaction: Transaction,
+    module: &PlannedFile,
+    download: impl FnOnce() -> Result<(), String>,
+    render: impl FnOnce() -> String,
+) -> Result<(), String> {
+    let result = download().and_then(|()| {
+        let contents = render();
+        if contents == module.contents {
+            return Ok(());
         }
+        transaction
+            .write(&module.path, &contents)
+            .map_err(|e| format!("failed to write \"{}\": {e}", module.path))
+    });
+
+    if result.is_err() {
+        transaction.rollback();
+    }
+    result
+}
+
+pub fn handle(
+    id: PuzzleId,
+    overwrite: bool,
+    template: &str,
+    download: Option<Download>,
+    dry_run: bool,
+    print_template: bool,
+) {
+    let template = load_template(template);
+
+    if print_template {
+        println!(
+            "{ANSI_ITALIC}Template `{}` from {}{ANSI_RESET}",
+            template.name, template.source
+        );
+        println!("---");
+        print!("{}", render_module(id, &template));
+        return;
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    let files = plan(id, overwrite, &template);
+
+    if dry_run {
+        println!(
+            "{ANSI_ITALIC}Template `{}` from {}{ANSI_RESET}",
+            template.name, template.source
+        );
+        for file in &files {
+            println!(
+                "{ANSI_BOLD}{}{ANSI_RESET} {ANSI_ITALIC}({}){ANSI_RESET}",
+                file.path,
+                file.describe()
+            );
         }
+        println!("---");
+        println!("Dry run, nothing was written.");
+        return;
+    }
+
+    let transaction = match apply(id, &files) {
+        Ok(transaction) => transaction,
         Err(e) => {
-            eprintln!("Failed to create example file: {e}");
+            eprintln!(
+                "Failed to scaffold day {}, no files were changed: {e}",
+                id.day()
+            );
             process::exit(1);
         }
+    };
+
+    for file in &files {
+        match file.change {
+            Change::Create if file.contents.is_empty() => {
+                println!("Created empty {} \"{}\"", file.kind, file.path);
+            }
+            Change::Create => println!("Created {} \"{}\"", file.kind, file.path),
+            Change::Overwrite => println!("Replaced {} \"{}\"", file.kind, file.path),
+            Change::Keep => println!("Kept existing {} \"{}\"", file.kind, file.path),
+        }
+    }
+
+    // keep the scaffolded files only if the download succeeds as well.
+    if let Some(download) = download
+        && let Err(e) = download_into(
+            transaction,
+            &files[0],
+            || download(id),
+            || render_module(id, &template),
+        )
+    {
+        eprintln!("{e}");
+        eprintln!(
+            "Failed to download day {}, removed the scaffolded files again.",
+            id.day()
+        );
+        process::exit(1);
     }
 
+    let config = config();
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    if id.year() == config.year {
+        println!("🎄 Type `cargo solve {}` to run your solution.", id.day());
+    } else {
+        println!(
+            "🎄 Type `cargo solve {} --year {}` to run your solution.",
+            id.day(),
+            id.year()
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Change, PlannedFile, download_into};
+    use crate::template::transaction::Transaction;
+    use std::{cell::Cell, env, fs, path::Path, process};
+
+    /// Writes a module like `apply` does.
+    fn scaffold(dir: &Path, name: &str) -> (Transaction, PlannedFile) {
+        let module = PlannedFile {
+            path: dir.join(name).to_string_lossy().into_owned(),
+            kind: "module file",
+            contents: "// Day 8".into(),
+            change: Change::Create,
+        };
+        let mut transaction = Transaction::default();
+        transaction.write(&module.path, &module.contents).unwrap();
+        (transaction, module)
+    }
+
+    #[test]
+    fn renders_module_after_download() {
+        let dir = env::temp_dir().join(format!("aoc-scaffold-{}", process::id()));
+        let downloaded = Cell::new(false);
+
+        let (transaction, module) = scaffold(&dir, "2025_08.rs");
+        let render = || {
+            let title = if downloaded.get() { ": Playground" } else { "" };
+            format!("// Day 8{title}")
+        };
+        download_into(
+            transaction,
+            &module,
+            || {
+                downloaded.set(true);
+                Ok(())
+            },
+            render,
+        )
+        .unwrap();
+        assert_eq!(
+            fs::read_to_string(&module.path).unwrap(),
+            "// Day 8: Playground"
+        );
+
+        let (transaction, module) = scaffold(&dir, "2025_09.rs");
+        let result = download_into(
+            transaction,
+            &module,
+            || Err("offline".into()),
+            || unreachable!("rendered without a download"),
+        );
+        assert_eq!(result, Err("offline".into()));
+        assert!(!Path::new(&module.path).exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
 }
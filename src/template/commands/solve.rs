@@ -1,9 +1,13 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::{PuzzleId, runner::RunOptions, watch::watch};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+pub fn handle(id: PuzzleId, release: bool, dhat: bool, watch_files: bool, options: RunOptions) {
+    if watch_files {
+        watch(id, options, release);
+    }
+
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), id.to_string()];
 
     if dhat {
         cmd_args.extend([
@@ -17,11 +21,7 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
     }
 
     cmd_args.push("--".to_string());
-
-    if let Some(submit_part) = submit_part {
-        cmd_args.push("--submit".to_string());
-        cmd_args.push(submit_part.to_string());
-    }
+    cmd_args.extend(options.to_args());
 
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
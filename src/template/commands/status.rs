@@ -0,0 +1,98 @@
+use std::{collections::HashMap, process};
+
+use tinyjson::JsonValue;
+
+use crate::template::{
+    ANSI_BOLD, ANSI_GREEN, ANSI_ITALIC, ANSI_RED, ANSI_RESET, AllDays, Part, PuzzleId,
+    selection::DaySelection,
+    stars::read_stars,
+    status::{DayStatus, PartStatus},
+    timings::Timings,
+};
+
+fn format_part(status: PartStatus, benchmark: Option<&str>) -> String {
+    let color = match status {
+        PartStatus::Unsolved => "",
+        PartStatus::Submitted | PartStatus::Verified => ANSI_GREEN,
+    };
+    let text = match benchmark {
+        Some(benchmark) => format!("{status} ({benchmark})"),
+        None => status.to_string(),
+    };
+    format!("{color}{text:<20}{ANSI_RESET}")
+}
+
+fn print_table(year: u16, statuses: &[DayStatus]) {
+    println!("{ANSI_BOLD}Advent of Code {year}{ANSI_RESET}");
+    println!();
+    println!(
+        "Day | Solution | Input | {:<11} | Puzzle | Tests | {:<20} | {:<20}",
+        "Examples", "Part 1", "Part 2"
+    );
+
+    for status in statuses {
+        let tests = match status.examples_pass {
+            Some(true) => format!("{ANSI_GREEN}pass {ANSI_RESET}"),
+            Some(false) => format!("{ANSI_RED}fail {ANSI_RESET}"),
+            None => "-    ".into(),
+        };
+        let benchmark = |part: Part| status.timing.as_ref().and_then(|t| t.part(part));
+
+        println!(
+            " {} | {:<8} | {:<5} | {:<11} | {:<6} | {tests} | {} | {}",
+            status.day,
+            if status.scaffolded { "✓" } else { "-" },
+            status.input.to_string(),
+            format!("{}/{}", status.examples[0], status.examples[1]),
+            status.puzzle.to_string(),
+            format_part(status.parts[0], benchmark(Part::One)),
+            format_part(status.parts[1], benchmark(Part::Two)),
+        );
+    }
+}
+
+pub fn handle(year: u16, selection: &DaySelection, json: bool, run_tests: bool) {
+    let days = match selection.resolve(year) {
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("Invalid day selection: {e}");
+            process::exit(1);
+        }
+    };
+
+    if run_tests && !json {
+        println!("{ANSI_ITALIC}Running example tests, pass --no-tests to skip them.{ANSI_RESET}");
+    }
+
+    let stars = read_stars(year);
+    let timings = Timings::read_from_file(year);
+
+    let statuses: Vec<DayStatus> = AllDays::for_year(year)
+        .filter(|day| days.contains(day))
+        .map(|day| {
+            let id = PuzzleId::new(year, day).unwrap();
+            DayStatus::collect(id, &stars, &timings, run_tests)
+        })
+        .collect();
+
+    if json {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        map.insert("year".into(), JsonValue::Number(f64::from(year)));
+        map.insert(
+            "days".into(),
+            JsonValue::Array(statuses.iter().map(JsonValue::from).collect()),
+        );
+        match JsonValue::Object(map).format() {
+            Ok(json) => println!("{json}"),
+            Err(e) => {
+                eprintln!("Failed to serialize status: {e}");
+                process::exit(1);
+            }
+        }
+    } else {
+        if run_tests {
+            println!();
+        }
+        print_table(year, &statuses);
+    }
+}
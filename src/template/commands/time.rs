@@ -1,34 +1,44 @@
-use std::collections::HashSet;
+use std::process;
 
+use crate::template::readme_benchmarks;
 use crate::template::run_multi::run_multi;
+use crate::template::selection::DaySelection;
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
-
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
-
-    let days_to_run = day.map_or_else(
-        || {
-            if run_all {
-                all_days().collect()
-            } else {
-                // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
-                    .collect()
-            }
-        },
-        |day| HashSet::from([day]),
-    );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+pub fn handle(year: u16, selection: &DaySelection, run_all: bool, store: bool) {
+    let stored_timings = Timings::read_from_file(year);
+
+    // when neither the `--all` flag nor a selection is set, filter out days that are fully benched.
+    let selection = if selection.is_empty() && !run_all {
+        DaySelection {
+            unbenched: true,
+            ..DaySelection::default()
+        }
+    } else {
+        selection.clone()
+    };
+
+    let days_to_run = match selection.resolve(year) {
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("Invalid day selection: {e}");
+            process::exit(1);
+        }
+    };
+
+    if days_to_run.is_empty() {
+        println!("No days match the selection.");
+        return;
+    }
+
+    let timings = run_multi(year, &days_to_run, true, true).unwrap();
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings.store_file(year).unwrap();
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(year, merged_timings) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
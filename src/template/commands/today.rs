@@ -0,0 +1,187 @@
+use std::{
+    io::{Write, stdout},
+    path::Path,
+    process, thread,
+    time::Duration,
+};
+
+use chrono::{DateTime, FixedOffset, Utc};
+
+use crate::template::{
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, PuzzleId,
+    aoc_cli::{self, AocCommandError},
+    commands::{
+        download, read,
+        scaffold::{self, Download},
+    },
+    config::config,
+    session::{self, SessionError},
+    templates::DEFAULT_TEMPLATE,
+};
+
+/// Delays between download attempts. Inputs can take a moment to become available after unlock.
+const RETRY_DELAYS: [Duration; 5] = [
+    Duration::from_secs(5),
+    Duration::from_secs(10),
+    Duration::from_secs(20),
+    Duration::from_secs(30),
+    Duration::from_secs(60),
+];
+
+fn format_countdown(seconds: i64) -> String {
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        (seconds % 3600) / 60,
+        seconds % 60
+    )
+}
+
+/// Checks aoc-cli and the session up front, so an expired session is not noticed only after the countdown.
+fn check_setup() {
+    if aoc_cli::check().is_err() {
+        eprintln!(
+            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+        );
+        process::exit(1);
+    }
+
+    match session::check() {
+        Ok(_) => {}
+        Err(e @ (SessionError::Missing | SessionError::Expired)) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+        Err(e) => eprintln!("Warning: could not validate session token: {e}"),
+    }
+}
+
+fn wait_for_unlock(id: PuzzleId, unlock: DateTime<FixedOffset>) {
+    let mut stdout = stdout();
+    loop {
+        let remaining = (unlock.with_timezone(&Utc) - Utc::now()).num_milliseconds();
+        if remaining <= 0 {
+            break;
+        }
+
+        // round up, so the countdown reaches zero at the unlock instant.
+        let seconds = (remaining + 999) / 1000;
+        print!(
+            "\r⏳ {ANSI_BOLD}Day {}{ANSI_RESET} of {} unlocks in {ANSI_BOLD}{}{ANSI_RESET} ",
+            id.day(),
+            id.year(),
+            format_countdown(seconds)
+        );
+        let _ = stdout.flush();
+
+        let until_next_second = remaining - (seconds - 1) * 1000;
+        thread::sleep(Duration::from_millis(until_next_second as u64));
+    }
+
+    println!(
+        "\r🎄 {ANSI_BOLD}Day {}{ANSI_RESET} is unlocked!{:24}",
+        id.day(),
+        ""
+    );
+}
+
+fn download_with_retries(id: PuzzleId) -> Result<(), String> {
+    let mut delays = RETRY_DELAYS.iter();
+    loop {
+        let e = match aoc_cli::download(id) {
+            Ok(_) => return Ok(()),
+            Err(e) => e,
+        };
+
+        // retrying does not help when the session is missing or expired.
+        let delay = match (&e, delays.next()) {
+            (AocCommandError::Session(_), _) | (_, None) => {
+                return Err(format!("failed to call aoc-cli: {e}"));
+            }
+            (_, Some(delay)) => delay,
+        };
+
+        println!(
+            "{ANSI_ITALIC}Download failed ({e}), retrying in {}s...{ANSI_RESET}",
+            delay.as_secs()
+        );
+        thread::sleep(*delay);
+    }
+}
+
+/// Checks that `year`, the one given with `--year`, is the event of today's puzzle.
+fn check_year(year: Option<u16>, id: PuzzleId) {
+    if let Some(year) = year
+        && year != id.year()
+    {
+        eprintln!(
+            "`today` works on the puzzle of day {} of {}, got `--year {year}`. Use `scaffold` with a specific day instead.",
+            id.day(),
+            id.year()
+        );
+        process::exit(1);
+    }
+}
+
+pub fn handle(year: Option<u16>, wait: bool) {
+    let id = if wait {
+        let Some((id, unlock)) = PuzzleId::next_unlock() else {
+            eprintln!("Could not determine the next puzzle unlock.");
+            process::exit(1);
+        };
+        check_year(year, id);
+        check_setup();
+        wait_for_unlock(id, unlock);
+        id
+    } else {
+        match PuzzleId::today() {
+            Some(id) => {
+                check_year(year, id);
+                id
+            }
+            None => {
+                eprintln!(
+                    "`today` command can only be run on the days of the event in \
+                    december. Please use `scaffold` with a specific day, or `--wait` \
+                    for the next puzzle to unlock."
+                );
+                process::exit(1)
+            }
+        }
+    };
+
+    let download: Download = if wait {
+        download_with_retries
+    } else {
+        download::download
+    };
+
+    // scaffolding downloads the puzzle itself, so the module gets its title.
+    if Path::new(&config().bin_path(id)).exists() {
+        println!(
+            "Solution file \"{}\" already exists, skipping scaffold.",
+            config().bin_path(id)
+        );
+        if let Err(e) = download(id) {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    } else {
+        scaffold::handle(id, false, DEFAULT_TEMPLATE, Some(download), false, false);
+    }
+
+    read::handle(id);
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::format_countdown;
+
+    #[test]
+    fn formats_countdowns() {
+        assert_eq!(format_countdown(59), "00:00:59");
+        assert_eq!(format_countdown(3 * 3600 + 2 * 60 + 1), "03:02:01");
+    }
+}
@@ -0,0 +1,13 @@
+use std::process;
+
+use crate::template::session;
+
+pub fn handle() {
+    match session::check() {
+        Ok(user) => println!("🎄 Logged in as {user}."),
+        Err(e) => {
+            eprintln!("Not logged in: {e}");
+            process::exit(1);
+        }
+    }
+}
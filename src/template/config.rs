@@ -0,0 +1,478 @@
+/// Project configuration, loaded from an optional `aoc.toml` in the repository root.
+use std::{
+    env,
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    process,
+    str::FromStr,
+    sync::OnceLock,
+    time::Duration,
+};
+
+use toml::{Table, Value};
+
+use crate::template::{Part, PuzzleId, date::DateTime};
+
+pub static CONFIG_FILE_PATH: &str = "aoc.toml";
+
+/// Offset of the advent of code server time (EST) to UTC, in hours.
+const DEFAULT_SERVER_UTC_OFFSET: i32 = -5;
+
+/// The first year advent of code took place.
+const FIRST_YEAR: u16 = 2015;
+
+/// Locations of the files the template reads and writes, relative to the repository root.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Paths {
+    /// Contains a directory per year with `inputs/`, `examples/`, `puzzles/` and `timings.json`.
+    pub data: String,
+    /// Contains the solution binaries.
+    pub bin: String,
+    pub readme: String,
+    /// Contains the module templates of `cargo scaffold` that override the built-in ones, one `<name>.txt` per template.
+    pub templates: String,
+}
+
+/// Settings for `cargo time`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Approximate total time spent benching a single part.
+    pub target_duration: Duration,
+    pub min_samples: u128,
+    pub max_samples: u128,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    pub year: u16,
+    pub server_utc_offset: i32,
+    pub leaderboard_id: Option<u32>,
+    pub paths: Paths,
+    pub bench: BenchConfig,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            year: latest_event_year(DEFAULT_SERVER_UTC_OFFSET),
+            server_utc_offset: DEFAULT_SERVER_UTC_OFFSET,
+            leaderboard_id: None,
+            paths: Paths {
+                data: "data".into(),
+                bin: "src/bin".into(),
+                readme: "README.md".into(),
+                templates: ".aoc/templates".into(),
+            },
+            bench: BenchConfig {
+                target_duration: Duration::from_secs(1),
+                min_samples: 10,
+                max_samples: 50_000,
+            },
+        }
+    }
+}
+
+/// The year of the most recent event that has started.
+fn latest_event_year(utc_offset_hours: i32) -> u16 {
+    let now = DateTime::now(utc_offset_hours);
+    let year = if now.month == 12 {
+        now.year
+    } else {
+        now.year - 1
+    };
+    u16::try_from(year).unwrap_or(FIRST_YEAR)
+}
+
+fn check_year(year: u16, latest_year: u16) -> Result<(), ConfigError> {
+    if !(FIRST_YEAR..=latest_year).contains(&year) {
+        return Err(ConfigError(format!(
+            "`year` must be between {FIRST_YEAR} and {latest_year}, got {year}."
+        )));
+    }
+    Ok(())
+}
+
+/// The config directory of the user, i.e. `$XDG_CONFIG_HOME`, `%APPDATA%` or `~/.config`.
+#[must_use]
+pub fn user_config_dir() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("APPDATA").map(PathBuf::from))
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+}
+
+/// Returns the configuration of the project, loading it on first use.
+/// Exits the process if `aoc.toml` is invalid.
+pub fn config() -> &'static Config {
+    static CONFIG: OnceLock<Config> = OnceLock::new();
+    CONFIG.get_or_init(|| match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Invalid {CONFIG_FILE_PATH}: {e}");
+            process::exit(1);
+        }
+    })
+}
+
+impl Config {
+    /// Reads `aoc.toml`. If not present, returns the default configuration.
+    pub fn load() -> Result<Self, ConfigError> {
+        Self::load_from(Path::new(CONFIG_FILE_PATH))
+    }
+
+    fn load_from(path: &Path) -> Result<Self, ConfigError> {
+        match fs::read_to_string(path) {
+            Ok(s) => s.parse(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(ConfigError(format!("could not read file: {e}"))),
+        }
+    }
+
+    /// Directory holding the data files of a single event, i.e. `data/2025`.
+    #[must_use]
+    pub fn data_dir(&self, year: u16) -> String {
+        format!("{}/{year}", self.paths.data)
+    }
+
+    #[must_use]
+    pub fn input_path(&self, id: PuzzleId) -> String {
+        format!("{}/inputs/{}.txt", self.data_dir(id.year()), id.day())
+    }
+
+    #[must_use]
+    pub fn example_path(&self, id: PuzzleId) -> String {
+        format!("{}/examples/{}.txt", self.data_dir(id.year()), id.day())
+    }
+
+    /// Example of a single part, for puzzles whose parts use different examples, i.e. `data/2025/examples/08-2.txt`.
+    #[must_use]
+    pub fn part_example_path(&self, id: PuzzleId, part: Part) -> String {
+        format!(
+            "{}/examples/{}-{part}.txt",
+            self.data_dir(id.year()),
+            id.day()
+        )
+    }
+
+    /// The example a part runs against: its part-specific example if present, the shared example otherwise.
+    #[must_use]
+    pub fn resolve_example_path(&self, id: PuzzleId, part: Part) -> String {
+        let part_path = self.part_example_path(id, part);
+        if Path::new(&part_path).exists() {
+            part_path
+        } else {
+            self.example_path(id)
+        }
+    }
+
+    #[must_use]
+    pub fn puzzle_path(&self, id: PuzzleId) -> String {
+        format!("{}/puzzles/{}.md", self.data_dir(id.year()), id.day())
+    }
+
+    #[must_use]
+    pub fn bin_path(&self, id: PuzzleId) -> String {
+        format!("{}/{id}.rs", self.paths.bin)
+    }
+
+    #[must_use]
+    pub fn timings_path(&self, year: u16) -> String {
+        format!("{}/timings.json", self.data_dir(year))
+    }
+
+    #[must_use]
+    pub fn titles_path(&self, year: u16) -> String {
+        format!("{}/titles.json", self.data_dir(year))
+    }
+
+    /// The configured readme holds the benchmarks of the configured year,
+    /// other years keep theirs next to their data.
+    #[must_use]
+    pub fn readme_path(&self, year: u16) -> String {
+        if year == self.year {
+            self.paths.readme.clone()
+        } else {
+            format!("{}/README.md", self.data_dir(year))
+        }
+    }
+
+    /// Parses the year of an event that has already started or is configured, e.g. for `--year`.
+    pub fn parse_year(&self, s: &str) -> Result<u16, ConfigError> {
+        let year = s
+            .parse()
+            .map_err(|_| ConfigError(format!("expected a year, got `{s}`.")))?;
+        let latest_year = latest_event_year(self.server_utc_offset)
+            .max(FIRST_YEAR)
+            .max(self.year);
+        check_year(year, latest_year)?;
+        Ok(year)
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        // the configured year may be the upcoming event, e.g. after `cargo new-year` in november.
+        let upcoming_year = latest_event_year(self.server_utc_offset).max(FIRST_YEAR) + 1;
+        check_year(self.year, upcoming_year)?;
+
+        if !(-12..=14).contains(&self.server_utc_offset) {
+            return Err(ConfigError(format!(
+                "`server_utc_offset` must be between -12 and 14, got {}.",
+                self.server_utc_offset
+            )));
+        }
+
+        for (key, path) in [
+            ("data", &self.paths.data),
+            ("bin", &self.paths.bin),
+            ("readme", &self.paths.readme),
+            ("templates", &self.paths.templates),
+        ] {
+            if path.is_empty() {
+                return Err(ConfigError(format!("`paths.{key}` must not be empty.")));
+            }
+        }
+
+        if self.bench.target_duration.is_zero() {
+            return Err(ConfigError("`bench.target_ms` must be positive.".into()));
+        }
+
+        if self.bench.min_samples == 0 || self.bench.min_samples > self.bench.max_samples {
+            return Err(ConfigError(format!(
+                "`bench.min_samples` must be between 1 and `bench.max_samples` ({}), got {}.",
+                self.bench.max_samples, self.bench.min_samples
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An error which can be returned when loading the [`Config`].
+#[derive(Debug, PartialEq, Eq)]
+pub struct ConfigError(String);
+
+impl Error for ConfigError {}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+fn get_integer<T: TryFrom<i64>>(value: &Value, key: &str) -> Result<T, ConfigError> {
+    value
+        .as_integer()
+        .and_then(|x| T::try_from(x).ok())
+        .ok_or_else(|| ConfigError(format!("expected `{key}` to be a valid integer.")))
+}
+
+fn get_table<'a>(value: &'a Value, key: &str) -> Result<&'a Table, ConfigError> {
+    value
+        .as_table()
+        .ok_or_else(|| ConfigError(format!("expected `{key}` to be a table.")))
+}
+
+/// Normalizes a path from the config file, e.g. `./src/bin/` becomes `src/bin`.
+fn get_path(value: &Value, key: &str) -> Result<String, ConfigError> {
+    let path = value
+        .as_str()
+        .ok_or_else(|| ConfigError(format!("expected `{key}` to be a string.")))?;
+    let path = path.strip_prefix("./").unwrap_or(path);
+    Ok(path.trim_end_matches('/').to_string())
+}
+
+fn unknown_key(key: &str) -> ConfigError {
+    ConfigError(format!("unknown key `{key}`."))
+}
+
+impl FromStr for Config {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let table: Table = s
+            .parse()
+            .map_err(|e| ConfigError(format!("not valid TOML: {e}")))?;
+
+        let mut config = Config::default();
+        let mut year = None;
+
+        for (key, value) in &table {
+            match key.as_str() {
+                "year" => year = Some(get_integer(value, key)?),
+                "server_utc_offset" => config.server_utc_offset = get_integer(value, key)?,
+                "paths" => {
+                    for (key, value) in get_table(value, key)? {
+                        let path = get_path(value, &format!("paths.{key}"))?;
+                        match key.as_str() {
+                            "data" => config.paths.data = path,
+                            "bin" => config.paths.bin = path,
+                            "readme" => config.paths.readme = path,
+                            "templates" => config.paths.templates = path,
+                            key => return Err(unknown_key(&format!("paths.{key}"))),
+                        }
+                    }
+                }
+                "bench" => {
+                    for (key, value) in get_table(value, key)? {
+                        let full_key = format!("bench.{key}");
+                        match key.as_str() {
+                            "target_ms" => {
+                                config.bench.target_duration =
+                                    Duration::from_millis(get_integer(value, &full_key)?);
+                            }
+                            "min_samples" => {
+                                config.bench.min_samples =
+                                    get_integer::<u64>(value, &full_key)?.into();
+                            }
+                            "max_samples" => {
+                                config.bench.max_samples =
+                                    get_integer::<u64>(value, &full_key)?.into();
+                            }
+                            _ => return Err(unknown_key(&full_key)),
+                        }
+                    }
+                }
+                "leaderboard" => {
+                    for (key, value) in get_table(value, key)? {
+                        match key.as_str() {
+                            "id" => {
+                                config.leaderboard_id = Some(get_integer(value, "leaderboard.id")?)
+                            }
+                            key => return Err(unknown_key(&format!("leaderboard.{key}"))),
+                        }
+                    }
+                }
+                key => return Err(unknown_key(key)),
+            }
+        }
+
+        // the latest event depends on the configured offset, not the default one.
+        config.year = year.unwrap_or_else(|| latest_event_year(config.server_utc_offset));
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, process, time::Duration};
+
+    use super::Config;
+
+    #[test]
+    fn handles_empty_config() {
+        let config: Config = "".parse().unwrap();
+        assert_eq!(config, Config::default());
+    }
+
+    #[test]
+    fn handles_full_config() {
+        let config: Config = r#"
+            year = 2024
+            server_utc_offset = -4
+
+            [paths]
+            data = "./aoc/data/"
+            bin = "src/bin"
+            readme = "docs/README.md"
+            templates = "templates"
+
+            [bench]
+            target_ms = 500
+            min_samples = 5
+            max_samples = 100
+
+            [leaderboard]
+            id = 3031
+        "#
+        .parse()
+        .unwrap();
+
+        assert_eq!(config.year, 2024);
+        assert_eq!(config.server_utc_offset, -4);
+        assert_eq!(config.leaderboard_id, Some(3031));
+        assert_eq!(config.paths.data, "aoc/data");
+        assert_eq!(config.paths.readme, "docs/README.md");
+        assert_eq!(config.bench.target_duration, Duration::from_millis(500));
+        assert_eq!(config.bench.min_samples, 5);
+        assert_eq!(config.bench.max_samples, 100);
+        let id = crate::template::PuzzleId::new(2024, crate::day!(8)).unwrap();
+        assert_eq!(config.input_path(id), "aoc/data/2024/inputs/08.txt");
+        assert_eq!(config.bin_path(id), "src/bin/2024_08.rs");
+        assert_eq!(
+            config.part_example_path(id, crate::template::Part::Two),
+            "aoc/data/2024/examples/08-2.txt"
+        );
+        assert_eq!(
+            config.resolve_example_path(id, crate::template::Part::Two),
+            "aoc/data/2024/examples/08.txt"
+        );
+        assert_eq!(config.readme_path(2024), "docs/README.md");
+        assert_eq!(config.readme_path(2023), "aoc/data/2023/README.md");
+    }
+
+    #[test]
+    fn rejects_invalid_values() {
+        assert!("year = 2014".parse::<Config>().is_err());
+        assert!("year = \"2024\"".parse::<Config>().is_err());
+        assert!("server_utc_offset = 20".parse::<Config>().is_err());
+        assert!("[paths]\ndata = \"\"".parse::<Config>().is_err());
+        assert!("[bench]\nmin_samples = 0".parse::<Config>().is_err());
+        assert!(
+            "[bench]\nmin_samples = 10\nmax_samples = 5"
+                .parse::<Config>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn derives_default_year_from_offset() {
+        let config: Config = "server_utc_offset = 14".parse().unwrap();
+        assert_eq!(config.year, super::latest_event_year(14));
+        let config: Config = "server_utc_offset = -12".parse().unwrap();
+        assert_eq!(config.year, super::latest_event_year(-12));
+    }
+
+    #[test]
+    fn falls_back_to_defaults_only_without_file() {
+        let dir = env::temp_dir().join(format!("aoc-config-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let config = Config::load_from(&dir.join("aoc.toml")).unwrap();
+        assert_eq!(config.paths.data, "data");
+        // a directory can't be read as a file.
+        assert!(Config::load_from(&dir).is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn accepts_upcoming_year() {
+        let upcoming_year = super::latest_event_year(-5) + 1;
+        assert!(format!("year = {upcoming_year}").parse::<Config>().is_ok());
+        assert!(
+            format!("year = {}", upcoming_year + 1)
+                .parse::<Config>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn parses_years() {
+        let config = Config::default();
+        assert_eq!(config.parse_year("2016"), Ok(2016));
+        assert!(config.parse_year("2014").is_err());
+        assert!(config.parse_year("twenty").is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        assert!("yaer = 2024".parse::<Config>().is_err());
+        assert!("[paths]\ninput = \"data\"".parse::<Config>().is_err());
+    }
+}
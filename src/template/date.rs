@@ -0,0 +1,76 @@
+/// Minimal calendar arithmetic for the parts of the template that can't rely on the optional `chrono` dependency.
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A calendar date and the seconds elapsed since its midnight.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DateTime {
+    pub year: i64,
+    pub month: u32,
+    pub day: u32,
+    pub seconds: i64,
+}
+
+impl DateTime {
+    /// Converts a unix timestamp to a date in the given UTC offset.
+    pub fn from_unix(ts: i64, utc_offset_hours: i32) -> Self {
+        let ts = ts + i64::from(utc_offset_hours) * 3600;
+        let (days, seconds) = (ts.div_euclid(86_400), ts.rem_euclid(86_400));
+
+        // civil-from-days, see: https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = yoe + era * 400 + i64::from(month <= 2);
+
+        DateTime {
+            year,
+            month,
+            day,
+            seconds,
+        }
+    }
+
+    /// The current date in the given UTC offset.
+    pub fn now(utc_offset_hours: i32) -> Self {
+        let ts = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs() as i64);
+        Self::from_unix(ts, utc_offset_hours)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::DateTime;
+
+    #[test]
+    fn converts_unix_timestamps() {
+        assert_eq!(
+            DateTime::from_unix(0, 0),
+            DateTime {
+                year: 1970,
+                month: 1,
+                day: 1,
+                seconds: 0
+            }
+        );
+        // 2025-12-01 05:15:00 UTC is 00:15:00 at the server.
+        assert_eq!(
+            DateTime::from_unix(1_764_566_100, -5),
+            DateTime {
+                year: 2025,
+                month: 12,
+                day: 1,
+                seconds: 15 * 60
+            }
+        );
+        assert_eq!(DateTime::from_unix(951_782_400, 0).day, 29);
+    }
+}
@@ -2,14 +2,14 @@ use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
+use crate::template::config::config;
 #[cfg(feature = "today")]
-use chrono::{Datelike, FixedOffset, Utc};
-
-#[cfg(feature = "today")]
-const SERVER_UTC_OFFSET: i32 = -5;
+use chrono::{DateTime, Datelike, FixedOffset, TimeZone, Utc};
 
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
 ///
+/// Not every event has 25 days, see [`event_length`] for the days of a given year.
+///
 /// # Display
 /// This value displays as a two digit number.
 ///
@@ -31,24 +31,68 @@ impl Day {
         Some(Self(day))
     }
 
+    /// Creates a [`Day`] from the provided value if it's a day of the event in `year`,
+    /// returns [`None`] otherwise.
+    pub const fn new_for_year(day: u8, year: u16) -> Option<Self> {
+        if day > event_length(year) {
+            return None;
+        }
+        Self::new(day)
+    }
+
     /// Converts the [`Day`] into an [`u8`].
-    pub fn into_inner(self) -> u8 {
+    pub const fn into_inner(self) -> u8 {
         self.0
     }
 }
 
+/// Returns the number of puzzles of the event in `year`. Starting with 2025, events have 12 days.
+pub const fn event_length(year: u16) -> u8 {
+    if year >= 2025 { 12 } else { 25 }
+}
+
 #[cfg(feature = "today")]
-impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+impl PuzzleId {
+    /// Returns the current puzzle if it's a day of this year's event in december, `None` otherwise.
     pub fn today() -> Option<Self> {
-        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let offset = FixedOffset::east_opt(config().server_utc_offset * 3600)?;
         let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
-            Self::new(u8::try_from(today.day()).ok()?)
+        if today.month() == 12 {
+            let year = u16::try_from(today.year()).ok()?;
+            Self::new(
+                year,
+                Day::new_for_year(u8::try_from(today.day()).ok()?, year)?,
+            )
         } else {
             None
         }
     }
+
+    /// Returns the next puzzle to unlock and the instant it unlocks, i.e. midnight at the server.
+    pub fn next_unlock() -> Option<(Self, DateTime<FixedOffset>)> {
+        let offset = FixedOffset::east_opt(config().server_utc_offset * 3600)?;
+        Self::next_unlock_after(Utc::now().with_timezone(&offset))
+    }
+
+    fn next_unlock_after(now: DateTime<FixedOffset>) -> Option<(Self, DateTime<FixedOffset>)> {
+        let mut year = u16::try_from(now.year()).ok()?;
+        let mut day = 1;
+
+        if now.month() == 12 {
+            // during the event, today's puzzle has already unlocked at midnight.
+            day = u8::try_from(now.day()).ok()? + 1;
+            if day > event_length(year) {
+                year += 1;
+                day = 1;
+            }
+        }
+
+        let unlock = now
+            .timezone()
+            .with_ymd_and_hms(i32::from(year), 12, u32::from(day), 0, 0, 0)
+            .single()?;
+        Some((Self::new(year, Day::new(day)?)?, unlock))
+    }
 }
 
 impl Display for Day {
@@ -74,40 +118,140 @@ impl PartialOrd<u8> for Day {
 impl FromStr for Day {
     type Err = DayFromStrError;
 
+    /// Parses a day of the event in the configured year.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let day = s.parse().map_err(|_| DayFromStrError)?;
-        Self::new(day).ok_or(DayFromStrError)
+        Self::parse_for_year(s, config().year)
+    }
+}
+
+impl Day {
+    /// Parses a day of the event in `year`.
+    pub fn parse_for_year(s: &str, year: u16) -> Result<Self, DayFromStrError> {
+        let err = DayFromStrError {
+            last_day: event_length(year),
+        };
+        let day = s.parse().map_err(|_| err)?;
+        Self::new_for_year(day, year).ok_or(err)
     }
 }
 
 /// An error which can be returned when parsing a [`Day`].
-#[derive(Debug)]
-pub struct DayFromStrError;
+#[derive(Debug, Clone, Copy)]
+pub struct DayFromStrError {
+    last_day: u8,
+}
 
 impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 25")
+        write!(f, "expecting a day number between 1 and {}", self.last_day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Identifies a puzzle by the year of its event and its day.
+///
+/// # Display
+/// This value displays as the name of the solution binary, i.e. `<year>_<day>`.
+///
+/// ```
+/// # use advent_of_code::template::{Day, PuzzleId};
+/// let id = PuzzleId::new(2025, Day::new(8).unwrap()).unwrap();
+/// assert_eq!(id.to_string(), "2025_08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    year: u16,
+    day: Day,
+}
+
+impl PuzzleId {
+    /// Creates a [`PuzzleId`] if `day` is part of the event in `year`, returns [`None`] otherwise.
+    pub const fn new(year: u16, day: Day) -> Option<Self> {
+        if day.0 > event_length(year) {
+            return None;
+        }
+        Some(Self { year, day })
+    }
+
+    /// Parses a day number of the event in `year`.
+    pub fn parse_day(day: &str, year: u16) -> Result<Self, DayFromStrError> {
+        let day = Day::parse_for_year(day, year)?;
+        Ok(Self { year, day })
+    }
+
+    /// Parses the name of a solution binary, e.g. `2025_08`.
+    pub const fn from_bin_name(name: &str) -> Option<Self> {
+        let bytes = name.as_bytes();
+        if bytes.len() < 6 || bytes.len() > 7 || bytes[4] != b'_' {
+            return None;
+        }
+
+        let mut year: u16 = 0;
+        let mut day: u8 = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            let b = bytes[i];
+            if i != 4 {
+                if !b.is_ascii_digit() {
+                    return None;
+                }
+                if i < 4 {
+                    year = year * 10 + (b - b'0') as u16;
+                } else {
+                    day = day * 10 + (b - b'0');
+                }
+            }
+            i += 1;
+        }
+
+        match Day::new(day) {
+            Some(day) => Self::new(year, day),
+            None => None,
+        }
+    }
+
+    pub const fn year(self) -> u16 {
+        self.year
+    }
+
+    pub const fn day(self) -> Day {
+        self.day
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}_{}", self.year, self.day)
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of the event in the configured year.
 pub fn all_days() -> AllDays {
-    AllDays::new()
+    AllDays::for_year(config().year)
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent from the 1st to the last day of an event.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { current: 1 }
+        Self::for_year(config().year)
+    }
+
+    pub fn for_year(year: u16) -> Self {
+        Self {
+            current: 1,
+            last: event_length(year),
+        }
     }
 }
 
@@ -115,10 +259,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: the iterator starts at 1 and event lengths are never above 25.
         let day = Day(self.current);
         self.current += 1;
 
@@ -139,15 +283,32 @@ macro_rules! day {
     };
 }
 
+/// Creates the [`PuzzleId`] of the current solution binary in a const context.
+///
+/// The year is taken from the binary name, e.g. `2025_08` for day 8 of 2025.
+#[macro_export]
+macro_rules! puzzle_id {
+    ($day:expr) => {
+        const {
+            match $crate::template::PuzzleId::from_bin_name(env!("CARGO_BIN_NAME")) {
+                Some(id) if id.day().into_inner() == $day => id,
+                _ => panic!(
+                    "invalid solution binary name, expecting `<year>_<day>` matching the day number"
+                ),
+            }
+        }
+    };
+}
+
 /* -------------------------------------------------------------------------- */
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{Day, all_days};
+    use super::{AllDays, Day, PuzzleId, event_length};
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = AllDays::for_year(2024);
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -176,6 +337,62 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn all_days_iterator_for_short_events() {
+        let days: Vec<Day> = AllDays::for_year(2025).collect();
+        assert_eq!(days.len(), 12);
+        assert_eq!(days.last(), Some(&Day(12)));
+    }
+
+    #[test]
+    fn event_lengths() {
+        assert_eq!(event_length(2015), 25);
+        assert_eq!(event_length(2024), 25);
+        assert_eq!(event_length(2025), 12);
+        assert_eq!(Day::new_for_year(13, 2025), None);
+        assert_eq!(Day::new_for_year(13, 2024), Some(Day(13)));
+    }
+
+    #[cfg(feature = "today")]
+    #[test]
+    fn computes_next_unlock() {
+        use chrono::{FixedOffset, TimeZone};
+
+        let server = FixedOffset::west_opt(5 * 3600).unwrap();
+        let at = |month, day, hour| {
+            server
+                .with_ymd_and_hms(2025, month, day, hour, 0, 0)
+                .unwrap()
+        };
+
+        let (id, unlock) = PuzzleId::next_unlock_after(at(11, 30, 23)).unwrap();
+        assert_eq!(id.to_string(), "2025_01");
+        assert_eq!(unlock, at(12, 1, 0));
+
+        let (id, unlock) = PuzzleId::next_unlock_after(at(12, 7, 23)).unwrap();
+        assert_eq!(id.to_string(), "2025_08");
+        assert_eq!(unlock, at(12, 8, 0));
+
+        let (id, _) = PuzzleId::next_unlock_after(at(12, 12, 1)).unwrap();
+        assert_eq!(id.to_string(), "2026_01");
+    }
+
+    #[test]
+    fn parses_bin_names() {
+        let id = PuzzleId::from_bin_name("2025_08").unwrap();
+        assert_eq!(id.year(), 2025);
+        assert_eq!(id.day(), Day(8));
+        assert_eq!(id.to_string(), "2025_08");
+
+        assert_eq!(
+            PuzzleId::from_bin_name("2024_25").map(|id| id.day()),
+            Some(Day(25))
+        );
+        assert_eq!(PuzzleId::from_bin_name("2025_13"), None);
+        assert_eq!(PuzzleId::from_bin_name("08"), None);
+        assert_eq!(PuzzleId::from_bin_name("2025-08"), None);
+    }
 }
 
 /* -------------------------------------------------------------------------- */
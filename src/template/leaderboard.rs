@@ -0,0 +1,248 @@
+/// Parses the JSON of a private advent of code leaderboard.
+use std::{collections::HashMap, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::date::DateTime;
+
+/// Completion timestamps (unix seconds) of both parts of a single day.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DayCompletion {
+    pub part_1: Option<i64>,
+    pub part_2: Option<i64>,
+}
+
+impl DayCompletion {
+    /// Time it took from solving part one to solving part two, in seconds.
+    pub fn delta(&self) -> Option<i64> {
+        Some(self.part_2? - self.part_1?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Member {
+    pub name: String,
+    pub stars: u32,
+    pub local_score: u32,
+    /// Completions indexed by day number.
+    pub days: Vec<(u8, DayCompletion)>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Leaderboard {
+    pub event: String,
+    /// Members sorted by local score, highest first.
+    pub members: Vec<Member>,
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn get_number(json: &HashMap<String, JsonValue>, key: &str) -> Option<f64> {
+    json.get(key).and_then(|v| v.get::<f64>().copied())
+}
+
+fn parse_star_ts(level: Option<&JsonValue>) -> Result<Option<i64>, String> {
+    let Some(level) = level else {
+        return Ok(None);
+    };
+
+    level
+        .get::<HashMap<String, JsonValue>>()
+        .and_then(|level| get_number(level, "get_star_ts"))
+        .map(|ts| Some(ts as i64))
+        .ok_or_else(|| "Expected completion to have a numeric `get_star_ts`.".into())
+}
+
+impl TryFrom<&JsonValue> for Member {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected member to be a JSON object.")?;
+
+        let id = get_number(json, "id").ok_or("Expected member.id to be a number.")?;
+
+        // members without a public name are shown as anonymous users on the website.
+        let name = json
+            .get("name")
+            .and_then(|v| v.get::<String>())
+            .cloned()
+            .unwrap_or_else(|| format!("(anonymous user #{id})"));
+
+        let stars = get_number(json, "stars").ok_or("Expected member.stars to be a number.")?;
+
+        let local_score =
+            get_number(json, "local_score").ok_or("Expected member.local_score to be a number.")?;
+
+        let completions = json
+            .get("completion_day_level")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("Expected member.completion_day_level to be an object.")?;
+
+        let mut days = completions
+            .iter()
+            .map(|(day, levels)| {
+                let day = day
+                    .parse::<u8>()
+                    .map_err(|_| format!("Expected completion day `{day}` to be a number."))?;
+                let levels = levels
+                    .get::<HashMap<String, JsonValue>>()
+                    .ok_or("Expected completion levels to be an object.")?;
+
+                Ok((
+                    day,
+                    DayCompletion {
+                        part_1: parse_star_ts(levels.get("1"))?,
+                        part_2: parse_star_ts(levels.get("2"))?,
+                    },
+                ))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        days.sort_unstable_by_key(|(day, _)| *day);
+
+        Ok(Member {
+            name,
+            stars: stars as u32,
+            local_score: local_score as u32,
+            days,
+        })
+    }
+}
+
+impl TryFrom<String> for Leaderboard {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let event = json
+            .get("event")
+            .and_then(|v| v.get::<String>())
+            .cloned()
+            .ok_or("expected JSON document to have key `event`.")?;
+
+        let mut members = json
+            .get("members")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("expected `json.members` to be an object.")?
+            .values()
+            .map(Member::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        members.sort_unstable_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.name.cmp(&b.name))
+        });
+
+        Ok(Leaderboard { event, members })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Formats a unix timestamp as `MM-DD hh:mm:ss` in the given UTC offset.
+pub fn format_timestamp(ts: i64, utc_offset_hours: i32) -> String {
+    let date = DateTime::from_unix(ts, utc_offset_hours);
+    format!(
+        "{:02}-{:02} {:02}:{:02}:{:02}",
+        date.month,
+        date.day,
+        date.seconds / 3600,
+        (date.seconds % 3600) / 60,
+        date.seconds % 60
+    )
+}
+
+/// Formats a duration in seconds in its two most significant units, e.g. `1h 05m`.
+pub fn format_delta(secs: i64) -> String {
+    let (days, hours, minutes, seconds) = (
+        secs / 86_400,
+        (secs % 86_400) / 3600,
+        (secs % 3600) / 60,
+        secs % 60,
+    );
+
+    if days > 0 {
+        format!("{days}d {hours:02}h")
+    } else if hours > 0 {
+        format!("{hours}h {minutes:02}m")
+    } else if minutes > 0 {
+        format!("{minutes}m {seconds:02}s")
+    } else {
+        format!("{seconds}s")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Leaderboard, format_delta, format_timestamp};
+
+    const LEADERBOARD: &str = r#"{
+        "event": "2025",
+        "owner_id": 1,
+        "members": {
+            "1": {
+                "id": 1, "name": "Jane", "stars": 3, "local_score": 10, "global_score": 0, "last_star_ts": 1764570000,
+                "completion_day_level": {
+                    "2": { "1": { "get_star_ts": 1764655800, "star_index": 3 } },
+                    "1": {
+                        "1": { "get_star_ts": 1764566100, "star_index": 1 },
+                        "2": { "get_star_ts": 1764566805, "star_index": 2 }
+                    }
+                }
+            },
+            "2": {
+                "id": 2, "name": null, "stars": 0, "local_score": 0, "global_score": 0, "last_star_ts": 0,
+                "completion_day_level": {}
+            }
+        }
+    }"#;
+
+    #[test]
+    fn parses_members() {
+        let leaderboard = Leaderboard::try_from(LEADERBOARD.to_string()).unwrap();
+        assert_eq!(leaderboard.event, "2025");
+        assert_eq!(leaderboard.members.len(), 2);
+
+        let jane = &leaderboard.members[0];
+        assert_eq!(jane.name, "Jane");
+        assert_eq!(jane.stars, 3);
+        assert_eq!(jane.local_score, 10);
+        assert_eq!(jane.days[0].0, 1);
+        assert_eq!(jane.days[0].1.delta(), Some(705));
+        assert_eq!(jane.days[1].0, 2);
+        assert_eq!(jane.days[1].1.delta(), None);
+
+        assert_eq!(leaderboard.members[1].name, "(anonymous user #2)");
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_leaderboard() {
+        Leaderboard::try_from(r#"{ "event": "2025" }"#.to_string()).unwrap();
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        // 2025-12-01 05:15:00 UTC is 00:15:00 at the server.
+        assert_eq!(format_timestamp(1764566100, -5), "12-01 00:15:00");
+        assert_eq!(format_timestamp(1764566100, 0), "12-01 05:15:00");
+    }
+
+    #[test]
+    fn formats_deltas() {
+        assert_eq!(format_delta(42), "42s");
+        assert_eq!(format_delta(705), "11m 45s");
+        assert_eq!(format_delta(3900), "1h 05m");
+        assert_eq!(format_delta(90_000), "1d 01h");
+    }
+}
@@ -1,59 +1,110 @@
-use std::{env, fs};
+use std::{
+    env, fs,
+    io::{self, Write},
+};
 
 pub mod aoc_cli;
 pub mod commands;
+pub mod config;
 pub mod runner;
+pub mod selection;
+pub mod session;
+pub mod templates;
 
 pub use day::*;
+pub use part::*;
 
+use config::config;
+
+mod answers;
+mod archive;
+mod date;
 mod day;
+mod leaderboard;
+mod part;
+mod puzzle;
 mod readme_benchmarks;
 mod run_multi;
+mod stars;
+mod status;
 mod timings;
+mod titles;
+mod transaction;
+mod watch;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
+pub const ANSI_RED: &str = "\x1b[31m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Asks a yes/no question on the command-line. Anything but `y` or `yes` counts as a no.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn confirm(question: &str) -> bool {
+    print!("{question} [y/N] ");
+    let _ = io::stdout().flush();
+
+    let mut answer = String::new();
+    if io::stdin().read_line(&mut answer).is_err() {
+        return false;
+    }
+
+    matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
+/// Helper function that reads a text file of the puzzle's year to a string.
+#[must_use]
+pub fn read_file(folder: &str, id: PuzzleId) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join(config().data_dir(id.year()))
+        .join(folder)
+        .join(format!("{}.txt", id.day()));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, id: PuzzleId, part: Part) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
+        .join(config().data_dir(id.year()))
         .join(folder)
-        .join(format!("{day}-{part}.txt"));
+        .join(format!("{}-{part}.txt", id.day()));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
+/// Helper function that reads the example of a part to a string.
+/// Uses a part-specific example like `01-2.txt` if present, and `01.txt` otherwise.
+#[must_use]
+pub fn read_example(id: PuzzleId, part: Part) -> String {
+    let cwd = env::current_dir().unwrap();
+    let filepath = cwd.join(config().resolve_example_path(id, part));
+    let f = fs::read_to_string(filepath);
+    f.expect("could not open example file")
+}
+
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
+/// The year of the puzzle is taken from the name of the binary, e.g. `2025_08`.
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!(@impl $day, [part_one, One] [part_two, Two]);
     };
     ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+        $crate::solution!(@impl $day, [part_one, One]);
     };
     ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+        $crate::solution!(@impl $day, [part_two, Two]);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
-        /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+    (@impl $day:expr, $( [$func:expr, $part:ident] )*) => {
+        /// The current puzzle.
+        const DAY: $crate::template::PuzzleId = $crate::puzzle_id!($day);
 
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
@@ -61,8 +112,18 @@ macro_rules! solution {
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            use ::std::borrow::Cow;
+            let options = RunOptions::from_env();
+            // with `--example`, every part runs against its own example instead of the input.
+            let input = (!options.example).then(|| $crate::template::read_file("inputs", DAY));
+            $(
+                let part = $crate::template::Part::$part;
+                let part_input = match &input {
+                    Some(input) => Cow::Borrowed(input.as_str()),
+                    None => Cow::Owned($crate::template::read_example(DAY, part)),
+                };
+                run_part($func, &*part_input, DAY, part, &options);
+            )*
         }
     };
 }
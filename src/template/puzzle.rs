@@ -0,0 +1,546 @@
+//! Parses the puzzle descriptions that aoc-cli saves to `data/puzzles`.
+//!
+//! aoc-cli converts the puzzle html to a small subset of markdown: headings like `\--- Day 1: Title ---`
+//! with a `---` underline, paragraphs, `* ` list items and fenced code blocks. Inline emphasis is
+//! rendered as `*text*` and inline code as `` `text` ``.
+
+use std::mem;
+
+use crate::template::{ANSI_BOLD, ANSI_GREEN, ANSI_RESET};
+
+/// A block-level element of a puzzle description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Block {
+    Heading(String),
+    Paragraph(String),
+    ListItem(String),
+    Code(String),
+}
+
+/// The example input and expected example answer of a single puzzle part.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PartExample {
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+/// Splits a puzzle description into its block-level elements.
+pub fn parse_blocks(markdown: &str) -> Vec<Block> {
+    let mut blocks = vec![];
+    let mut paragraph: Vec<&str> = vec![];
+    let mut code: Option<Vec<&str>> = None;
+
+    let flush = |paragraph: &mut Vec<&str>, blocks: &mut Vec<Block>| {
+        if !paragraph.is_empty() {
+            blocks.push(Block::Paragraph(paragraph.join(" ")));
+            paragraph.clear();
+        }
+    };
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            match code.take() {
+                Some(lines) => blocks.push(Block::Code(lines.join("\n"))),
+                None => {
+                    flush(&mut paragraph, &mut blocks);
+                    code = Some(vec![]);
+                }
+            }
+            continue;
+        }
+
+        if let Some(lines) = code.as_mut() {
+            lines.push(line);
+            continue;
+        }
+
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() {
+            flush(&mut paragraph, &mut blocks);
+        } else if is_underline(trimmed) {
+            // setext-style underline of the preceding heading.
+            flush(&mut paragraph, &mut blocks);
+        } else if let Some(heading) = parse_heading(trimmed) {
+            flush(&mut paragraph, &mut blocks);
+            blocks.push(Block::Heading(heading));
+        } else if let Some(item) = trimmed
+            .strip_prefix("* ")
+            .or_else(|| trimmed.strip_prefix("- "))
+        {
+            flush(&mut paragraph, &mut blocks);
+            blocks.push(Block::ListItem(item.to_string()));
+        } else {
+            paragraph.push(trimmed);
+        }
+    }
+
+    if let Some(lines) = code {
+        blocks.push(Block::Code(lines.join("\n")));
+    }
+    flush(&mut paragraph, &mut blocks);
+
+    blocks
+}
+
+fn is_underline(line: &str) -> bool {
+    line.len() >= 3 && line.chars().all(|c| c == '-' || c == '=')
+}
+
+fn parse_heading(line: &str) -> Option<String> {
+    if let Some(heading) = line.strip_prefix('#') {
+        return Some(heading.trim_start_matches('#').trim().to_string());
+    }
+
+    let heading = line
+        .strip_prefix("\\---")
+        .or_else(|| line.strip_prefix("---"))?
+        .strip_suffix("---")?;
+
+    Some(heading.trim().to_string())
+}
+
+/// Groups blocks by puzzle part. The first heading starts part one, a `Part Two` heading starts part two.
+fn split_parts(blocks: &[Block]) -> Vec<&[Block]> {
+    let starts: Vec<usize> = blocks
+        .iter()
+        .enumerate()
+        .filter(|(_, block)| matches!(block, Block::Heading(h) if h.starts_with("Day ") || h.starts_with("Part ")))
+        .map(|(i, _)| i)
+        .collect();
+
+    starts
+        .iter()
+        .enumerate()
+        .map(|(i, &start)| {
+            let end = starts.get(i + 1).copied().unwrap_or(blocks.len());
+            &blocks[start..end]
+        })
+        .collect()
+}
+
+/// Returns the text of every emphasized inline code span (`` `*42*` `` or `` *`42`* ``) in `text`.
+pub fn emphasized_code(text: &str) -> Vec<String> {
+    let mut values = vec![];
+    let mut rest = text;
+
+    while let Some(pos) = rest.find(['`', '*']) {
+        let (open, close) = if rest[pos..].starts_with("`*") {
+            ("`*", "*`")
+        } else if rest[pos..].starts_with("*`") {
+            ("*`", "`*")
+        } else {
+            rest = &rest[pos + 1..];
+            continue;
+        };
+
+        let inner = &rest[pos + open.len()..];
+        match inner.find(close) {
+            Some(end) => {
+                values.push(inner[..end].to_string());
+                rest = &inner[end + close.len()..];
+            }
+            None => break,
+        }
+    }
+
+    values
+}
+
+/// Finds the example input and the expected example answer for every puzzle part.
+///
+/// The example is the first code block of a part and the answer is the last emphasized inline code
+/// of its text, which is where the puzzles state the result for the example.
+/// Parts that don't contain an example of their own inherit the example of the previous part.
+pub fn extract_examples(markdown: &str) -> Vec<PartExample> {
+    let blocks = parse_blocks(markdown);
+    let mut examples: Vec<PartExample> = vec![];
+
+    for part in split_parts(&blocks) {
+        let input = part
+            .iter()
+            .find_map(|block| match block {
+                Block::Code(code) if code.contains('\n') => Some(code.clone()),
+                _ => None,
+            })
+            .or_else(|| examples.last().and_then(|e| e.input.clone()));
+
+        let answer = part
+            .iter()
+            .filter_map(|block| match block {
+                Block::Paragraph(text) | Block::ListItem(text)
+                    if !text.starts_with("Your puzzle answer was") =>
+                {
+                    emphasized_code(text).pop()
+                }
+                _ => None,
+            })
+            .next_back();
+
+        examples.push(PartExample { input, answer });
+    }
+
+    examples
+}
+
+/// Finds the accepted answers, which the website adds to the description of solved parts.
+pub fn extract_answers(markdown: &str) -> Vec<String> {
+    parse_blocks(markdown)
+        .iter()
+        .filter_map(|block| match block {
+            Block::Paragraph(text) => text
+                .strip_prefix("Your puzzle answer was `")?
+                .split('`')
+                .next()
+                .map(ToString::to_string),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Finds the heading of the puzzle, e.g. `Day 8: Playground`.
+pub fn extract_title(markdown: &str) -> Option<String> {
+    parse_blocks(markdown)
+        .into_iter()
+        .find_map(|block| match block {
+            Block::Heading(heading) if heading.starts_with("Day ") => Some(heading),
+            _ => None,
+        })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Style {
+    emphasis: bool,
+    code: bool,
+}
+
+impl Style {
+    fn ansi(self) -> String {
+        let mut codes = String::new();
+        if self.emphasis {
+            codes.push_str(ANSI_BOLD);
+        }
+        if self.code {
+            codes.push_str(ANSI_GREEN);
+        }
+        codes
+    }
+}
+
+/// A word of a paragraph, made up of differently styled pieces, e.g. `` `*40*`. ``.
+type Word = Vec<(String, Style)>;
+
+fn flush_piece(piece: &mut String, style: Style, word: &mut Word) {
+    if !piece.is_empty() {
+        word.push((mem::take(piece), style));
+    }
+}
+
+fn flush_word(piece: &mut String, style: Style, word: &mut Word, words: &mut Vec<Word>) {
+    flush_piece(piece, style, word);
+    if !word.is_empty() {
+        words.push(mem::take(word));
+    }
+}
+
+/// The length of the `(url)` at the start of `rest`, urls may contain balanced parentheses.
+fn url_len(rest: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in rest.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 1 => return Some(i + 1),
+            ')' => depth -= 1,
+            _ if depth == 0 => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Whether `rest`, the text after a `[`, continues as a link like `text](url)`.
+fn is_link(rest: &str) -> bool {
+    rest.split_once(']')
+        .is_some_and(|(text, url)| !text.contains('[') && url_len(url).is_some())
+}
+
+/// Splits a line of inline markdown into words, resolving emphasis, inline code, links and escapes.
+///
+/// Inline code is taken literally, except for emphasis around all of it like in `` `*40*` ``.
+/// Links are shown by their text only.
+fn styled_words(text: &str) -> Vec<Word> {
+    let mut words: Vec<Word> = vec![];
+    let mut word: Word = vec![];
+    let mut piece = String::new();
+    let mut style = Style::default();
+    let mut in_link = false;
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(escaped) = chars.next() {
+                    piece.push(escaped);
+                }
+            }
+            '`' => {
+                let rest = chars.as_str();
+                let Some(end) = rest.find('`') else {
+                    piece.push(c);
+                    continue;
+                };
+
+                flush_piece(&mut piece, style, &mut word);
+                let code = &rest[..end];
+                let emphasized = code
+                    .strip_prefix('*')
+                    .and_then(|code| code.strip_suffix('*'));
+                let code_style = Style {
+                    emphasis: style.emphasis || emphasized.is_some(),
+                    code: true,
+                };
+
+                for c in emphasized.unwrap_or(code).chars() {
+                    if c.is_whitespace() {
+                        flush_word(&mut piece, code_style, &mut word, &mut words);
+                    } else {
+                        piece.push(c);
+                    }
+                }
+                flush_piece(&mut piece, code_style, &mut word);
+                chars = rest[end + 1..].chars();
+            }
+            '*' => {
+                flush_piece(&mut piece, style, &mut word);
+                style.emphasis = !style.emphasis;
+            }
+            '[' if is_link(chars.as_str()) => in_link = true,
+            ']' if in_link => {
+                in_link = false;
+                // skip the url, `is_link` made sure it is closed.
+                let rest = chars.as_str();
+                chars = rest[url_len(rest).unwrap_or(0)..].chars();
+            }
+            c if c.is_whitespace() => flush_word(&mut piece, style, &mut word, &mut words),
+            c => piece.push(c),
+        }
+    }
+
+    flush_piece(&mut piece, style, &mut word);
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+/// Greedily wraps styled words into lines no wider than `width` visible characters.
+fn wrap(text: &str, width: usize, indent: &str) -> String {
+    let mut lines: Vec<String> = vec![];
+    let mut line = String::new();
+    let mut line_width = 0;
+
+    for word in styled_words(text) {
+        let word_width: usize = word.iter().map(|(s, _)| s.chars().count()).sum();
+
+        if line_width > 0 && line_width + 1 + word_width > width {
+            lines.push(mem::take(&mut line));
+            line_width = 0;
+        }
+
+        if line_width == 0 {
+            line.push_str(indent);
+            line_width = indent.chars().count();
+        } else {
+            line.push(' ');
+            line_width += 1;
+        }
+
+        for (piece, style) in word {
+            if style == Style::default() {
+                line.push_str(&piece);
+            } else {
+                line.push_str(&format!("{}{piece}{ANSI_RESET}", style.ansi()));
+            }
+        }
+        line_width += word_width;
+    }
+
+    if !line.is_empty() {
+        lines.push(line);
+    }
+
+    lines.join("\n")
+}
+
+/// Renders a puzzle description for the terminal, wrapping text to `width` columns.
+pub fn render(markdown: &str, width: usize) -> String {
+    parse_blocks(markdown)
+        .iter()
+        .map(|block| match block {
+            Block::Heading(heading) => {
+                format!("{ANSI_BOLD}{ANSI_GREEN}--- {heading} ---{ANSI_RESET}")
+            }
+            Block::Paragraph(text) => wrap(text, width, ""),
+            Block::ListItem(text) => {
+                let item = wrap(text, width.saturating_sub(2), "");
+                format!("• {}", item.replace('\n', "\n  "))
+            }
+            Block::Code(code) => code
+                .lines()
+                .map(|line| format!("    {ANSI_GREEN}{line}{ANSI_RESET}"))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        Block, emphasized_code, extract_answers, extract_examples, extract_title, parse_blocks,
+        render, styled_words,
+    };
+    use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+    const PUZZLE: &str = "\\--- Day 8: Playground ---
+----------
+
+The elves are *very* busy.
+
+For example:
+
+```
+1,2
+3,4
+```
+
+In this example, the answer is `*40*`.
+
+\\--- Part Two ---
+----------
+
+Now consider this:
+
+```
+5,6
+7,8
+```
+
+* One item
+* Another item
+
+Now the answer is *`25272`*.
+
+Your puzzle answer was `1234`.
+";
+
+    #[test]
+    fn parses_blocks() {
+        let blocks = parse_blocks(PUZZLE);
+        assert_eq!(blocks[0], Block::Heading("Day 8: Playground".into()));
+        assert_eq!(
+            blocks[1],
+            Block::Paragraph("The elves are *very* busy.".into())
+        );
+        assert_eq!(blocks[3], Block::Code("1,2\n3,4".into()));
+        assert_eq!(blocks[5], Block::Heading("Part Two".into()));
+        assert_eq!(blocks[8], Block::ListItem("One item".into()));
+    }
+
+    #[test]
+    fn finds_emphasized_code() {
+        assert_eq!(
+            emphasized_code("a `*1*`, *b*, *`2`* and `3`"),
+            vec!["1".to_string(), "2".to_string()]
+        );
+    }
+
+    #[test]
+    fn extracts_part_specific_examples() {
+        let examples = extract_examples(PUZZLE);
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].input.as_deref(), Some("1,2\n3,4"));
+        assert_eq!(examples[0].answer.as_deref(), Some("40"));
+        assert_eq!(examples[1].input.as_deref(), Some("5,6\n7,8"));
+        assert_eq!(examples[1].answer.as_deref(), Some("25272"));
+    }
+
+    #[test]
+    fn inherits_example_from_previous_part() {
+        let puzzle = "\\--- Day 1: A ---\n\n```\n1\n2\n```\n\nIt is `*3*`.\n\n\\--- Part Two ---\n\nNow it is `*4*`.";
+        let examples = extract_examples(puzzle);
+        assert_eq!(examples[1].input.as_deref(), Some("1\n2"));
+        assert_eq!(examples[1].answer.as_deref(), Some("4"));
+    }
+
+    #[test]
+    fn extracts_accepted_answers() {
+        assert_eq!(extract_answers(PUZZLE), vec!["1234".to_string()]);
+        assert!(extract_answers("\\--- Day 1: A ---\n\nNo answers yet.").is_empty());
+    }
+
+    #[test]
+    fn extracts_title() {
+        assert_eq!(extract_title(PUZZLE), Some("Day 8: Playground".into()));
+        assert_eq!(extract_title("No heading."), None);
+    }
+
+    #[test]
+    fn resolves_inline_styles() {
+        let words = styled_words("a `*40*`. \\*b");
+        assert_eq!(words.len(), 3);
+        assert_eq!(words[1].len(), 2);
+        assert_eq!(words[1][0].0, "40");
+        assert_eq!(words[1][1].0, ".");
+        assert_eq!(words[2][0].0, "*b");
+    }
+
+    #[test]
+    fn takes_inline_code_literally() {
+        let words = styled_words("`a*b` `*` `x * y` *c*");
+        let pieces: Vec<Vec<&str>> = words
+            .iter()
+            .map(|word| word.iter().map(|(s, _)| s.as_str()).collect())
+            .collect();
+        assert_eq!(
+            pieces,
+            vec![
+                vec!["a*b"],
+                vec!["*"],
+                vec!["x"],
+                vec!["*"],
+                vec!["y"],
+                vec!["c"]
+            ]
+        );
+        assert!(words[..5].iter().all(|word| !word[0].1.emphasis));
+        assert!(words[5][0].1.emphasis);
+    }
+
+    #[test]
+    fn renders_link_text() {
+        let rendered = render(
+            "see [the *wiki*](https://en.wikipedia.org/wiki/Graph_(discrete_mathematics)), [x]",
+            80,
+        );
+        assert_eq!(
+            rendered,
+            format!("see the {ANSI_BOLD}wiki{ANSI_RESET}, [x]")
+        );
+    }
+
+    #[test]
+    fn renders_wrapped_paragraphs() {
+        let rendered = render("one two *three* four", 10);
+        assert_eq!(
+            rendered,
+            format!("one two\n{ANSI_BOLD}three{ANSI_RESET} four")
+        );
+    }
+}
@@ -2,10 +2,12 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::template::Day;
+use crate::template::config::{Config, config};
 use crate::template::timings::Timings;
+use crate::template::titles::Titles;
+use crate::template::{PuzzleId, event_length};
 
-static MARKER: &str = "<!--- benchmarking table --->";
+pub static MARKER: &str = "<!--- benchmarking table --->";
 
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -25,11 +27,6 @@ pub struct TablePosition {
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     let matches: Vec<_> = readme.match_indices(MARKER).collect();
 
@@ -52,7 +49,22 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
+/// Relative path from the directory of `readme` back to the repository root.
+pub fn root_prefix(readme: &str) -> String {
+    match readme.matches('/').count() {
+        0 => "./".into(),
+        depth => "../".repeat(depth),
+    }
+}
+
+fn construct_table(
+    prefix: &str,
+    timings: Timings,
+    total_millis: f64,
+    year: u16,
+    titles: &Titles,
+    config: &Config,
+) -> String {
     let header = format!("{prefix} Benchmarks");
 
     let mut lines: Vec<String> = vec![
@@ -63,11 +75,22 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
         "| :---: | :---: | :---:  |".into(),
     ];
 
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+    let last_day = event_length(year);
+    let root = root_prefix(&config.readme_path(year));
+
+    for timing in timings.data.into_iter().filter(|t| t.day <= last_day) {
+        let Some(id) = PuzzleId::new(year, timing.day) else {
+            continue;
+        };
+        let path = format!("{root}{}", config.bin_path(id));
+        // e.g. `Day 8: Playground`, like the heading of the puzzle.
+        let name = match titles.resolve(id) {
+            Some(title) => format!("Day {}: {title}", timing.day.into_inner()),
+            None => format!("Day {}", timing.day.into_inner()),
+        };
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
+            "| [{}]({}) | `{}` | `{}` |",
+            name,
             path,
             timing.part_1.unwrap_or_else(|| "-".into()),
             timing.part_2.unwrap_or_else(|| "-".into())
@@ -81,26 +104,47 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
+fn update_content(
+    s: &mut String,
+    timings: Timings,
+    total_millis: f64,
+    year: u16,
+    titles: &Titles,
+    config: &Config,
+) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+    let table = construct_table("##", timings, total_millis, year, titles, config);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
+pub fn update(year: u16, timings: Timings) -> Result<(), Error> {
+    let config = config();
+    let path = &config.readme_path(year);
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    let titles = Titles::read_from_file(year);
+    update_content(&mut readme, timings, total_millis, year, &titles, config)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use super::{Error, MARKER, root_prefix, update_content};
+    use crate::{
+        day,
+        template::config::Config,
+        template::timings::{Timing, Timings},
+        template::titles::Titles,
+    };
+
+    fn get_mock_config() -> Config {
+        Config {
+            year: 2025,
+            ..Config::default()
+        }
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
@@ -127,32 +171,44 @@ mod tests {
         }
     }
 
+    /// Updates `s` with the mock timings and no titles.
+    fn update_mock_content(s: &mut String) -> Result<(), Error> {
+        update_content(
+            s,
+            get_mock_timings(),
+            190.0,
+            2025,
+            &Titles::default(),
+            &get_mock_config(),
+        )
+    }
+
     #[test]
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_mock_content(&mut s).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_mock_content(&mut s).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_mock_content(&mut s).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_mock_content(&mut s).unwrap();
+        update_mock_content(&mut s).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,7 +216,7 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_mock_content(&mut s).unwrap();
         let expected = [
             "foo",
             "bar",
@@ -169,9 +225,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2025_01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2025_02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2025_04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +236,28 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn names_days_with_titles() {
+        let mut titles = Titles::default();
+        titles.insert(day!(2), "Gift Shop".into());
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(
+            &mut s,
+            get_mock_timings(),
+            190.0,
+            2025,
+            &titles,
+            &get_mock_config(),
+        )
+        .unwrap();
+        assert!(s.contains("| [Day 1](./src/bin/2025_01.rs) | `10ms` | `20ms` |"));
+        assert!(s.contains("| [Day 2: Gift Shop](./src/bin/2025_02.rs) | `30ms` | `40ms` |"));
+    }
+
+    #[test]
+    fn links_archived_readmes_to_root() {
+        assert_eq!(root_prefix("README.md"), "./");
+        assert_eq!(root_prefix("data/2024/README.md"), "../../");
+    }
 }
@@ -1,19 +1,42 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{BTreeMap, HashSet},
+    io,
+    num::NonZero,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::template::{
+    ANSI_BOLD, ANSI_GREEN, ANSI_ITALIC, ANSI_RED, ANSI_RESET, AllDays, Day, PuzzleId,
+    runner::RunOptions,
+};
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use child_commands::SolutionOutput;
 
 use super::{
-    all_days,
     timings::{Timing, Timings},
+    titles::{Titles, header},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+/// Runs the solutions of the selected days one after another, streaming their output.
+/// Timed runs use this so benchmarks do not interfere with each other.
+pub fn run_multi(
+    year: u16,
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+) -> Option<Timings> {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
 
     let mut need_space = false;
+    let titles = Titles::read_from_file(year);
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
+    AllDays::for_year(year)
         .filter(|day| days_to_run.contains(day))
         .for_each(|day| {
             if need_space {
@@ -21,10 +44,18 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
             }
             need_space = true;
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+            let id = PuzzleId::new(year, day).unwrap();
+            println!(
+                "{ANSI_BOLD}{}{ANSI_RESET}",
+                header(day, titles.resolve(id).as_deref())
+            );
             println!("------");
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            let options = RunOptions {
+                time: is_timed,
+                ..RunOptions::default()
+            };
+            let output = child_commands::run_solution(id, options, is_release).unwrap();
 
             if output.is_empty() {
                 println!("Not solved.");
@@ -46,6 +77,161 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
     }
 }
 
+/// How the solution of a day fared in an untimed run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Outcome {
+    Passed,
+    Failed,
+    /// The day is not scaffolded or at least one part returned no result.
+    Unsolved,
+}
+
+impl Outcome {
+    fn of(output: Option<&SolutionOutput>) -> Self {
+        match output {
+            None => Outcome::Unsolved,
+            Some(output) if !output.success => Outcome::Failed,
+            Some(output) if output.stdout.iter().any(|line| line.contains('✖')) => {
+                Outcome::Unsolved
+            }
+            Some(output) if output.stdout.is_empty() => Outcome::Unsolved,
+            Some(_) => Outcome::Passed,
+        }
+    }
+}
+
+/// Counts of the outcomes of a parallel run.
+#[derive(Debug, Default)]
+pub struct Summary {
+    passed: usize,
+    failed: Vec<Day>,
+    unsolved: usize,
+    elapsed: Duration,
+}
+
+impl Summary {
+    fn add(&mut self, day: Day, outcome: Outcome) {
+        match outcome {
+            Outcome::Passed => self.passed += 1,
+            Outcome::Failed => self.failed.push(day),
+            Outcome::Unsolved => self.unsolved += 1,
+        }
+    }
+
+    pub fn has_failures(&self) -> bool {
+        !self.failed.is_empty()
+    }
+
+    pub fn print(&self) {
+        println!(
+            "\n{ANSI_BOLD}Summary:{ANSI_RESET} {ANSI_GREEN}{} passed{ANSI_RESET}, {}{} failed{ANSI_RESET}, {} unsolved {ANSI_ITALIC}({:.2?}){ANSI_RESET}",
+            self.passed,
+            if self.failed.is_empty() {
+                ANSI_RESET
+            } else {
+                ANSI_RED
+            },
+            self.failed.len(),
+            self.unsolved,
+            self.elapsed
+        );
+
+        if !self.failed.is_empty() {
+            let days: Vec<String> = self.failed.iter().map(ToString::to_string).collect();
+            println!("{ANSI_RED}Failed days: {}{ANSI_RESET}", days.join(", "));
+        }
+    }
+}
+
+fn print_day(day: Day, title: Option<&str>, output: Option<&SolutionOutput>, need_space: bool) {
+    if need_space {
+        println!();
+    }
+
+    println!("{ANSI_BOLD}{}{ANSI_RESET}", header(day, title));
+    println!("------");
+
+    let Some(output) = output else {
+        println!("Not solved.");
+        return;
+    };
+
+    for line in &output.stdout {
+        println!("{line}");
+    }
+    for line in &output.stderr {
+        eprintln!("{line}");
+    }
+
+    if output.stdout.is_empty() && output.success {
+        println!("Not solved.");
+    }
+}
+
+/// Runs the solutions of the selected days on a pool of worker threads without timing them.
+/// The output of every day is buffered and printed in day order.
+pub fn run_parallel(year: u16, days_to_run: &HashSet<Day>, is_release: bool) -> Summary {
+    let started = Instant::now();
+
+    let ids: Vec<PuzzleId> = AllDays::for_year(year)
+        .filter(|day| days_to_run.contains(day))
+        .map(|day| PuzzleId::new(year, day).unwrap())
+        .collect();
+
+    // build all solutions up front, concurrent `cargo run` invocations would wait for each other's builds.
+    child_commands::build_solutions(&ids, is_release);
+
+    let workers = thread::available_parallelism()
+        .map_or(1, NonZero::get)
+        .min(ids.len());
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    let mut summary = Summary::default();
+    let titles = Titles::read_from_file(year);
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            let sender = sender.clone();
+            let (ids, next) = (&ids, &next);
+            scope.spawn(move || {
+                while let Some(id) = ids.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let output = child_commands::capture_solution(*id, is_release);
+                    if sender.send((id.day(), output)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        // print every day as soon as all days before it are done.
+        let mut pending = BTreeMap::new();
+        let mut printed = 0;
+        for (day, output) in receiver {
+            pending.insert(day, output);
+            while let Some(output) = ids.get(printed).and_then(|id| pending.remove(&id.day())) {
+                let output = output.unwrap_or_else(|e| {
+                    eprintln!("Failed to run solution: {e:?}");
+                    Some(SolutionOutput::failed())
+                });
+                let title = titles.resolve(ids[printed]);
+                print_day(
+                    ids[printed].day(),
+                    title.as_deref(),
+                    output.as_ref(),
+                    printed > 0,
+                );
+                summary.add(ids[printed].day(), Outcome::of(output.as_ref()));
+                printed += 1;
+            }
+        }
+    });
+
+    summary.elapsed = started.elapsed();
+    summary
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
@@ -59,16 +245,11 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::Error;
+    use crate::template::{Day, Part, PuzzleId, config::config, runner::RunOptions};
     use std::{
         io::{BufRead, BufReader},
         path::Path,
@@ -76,26 +257,115 @@ pub mod child_commands {
         thread,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+    /// Output of a solution that ran to completion, buffered instead of forwarded.
+    #[derive(Debug)]
+    pub struct SolutionOutput {
+        pub stdout: Vec<String>,
+        pub stderr: Vec<String>,
+        pub success: bool,
+    }
+
+    impl SolutionOutput {
+        pub fn failed() -> Self {
+            SolutionOutput {
+                stdout: vec![],
+                stderr: vec![],
+                success: false,
+            }
+        }
+    }
+
+    fn is_scaffolded(id: PuzzleId) -> bool {
+        Path::new(&config().bin_path(id)).exists()
+    }
+
+    fn cargo_args(id: PuzzleId, options: RunOptions, is_release: bool) -> Vec<String> {
+        let mut args = vec![
+            "run".into(),
+            "--quiet".into(),
+            "--bin".into(),
+            id.to_string(),
+        ];
+
+        if is_release {
+            args.push("--release".into());
+        }
+
+        // mirror run options to child invocations, answers are never submitted from here.
+        let options = RunOptions {
+            submit: None,
+            ..options
+        };
+        args.push("--".into());
+        args.extend(options.to_args());
+        args
+    }
+
+    /// Builds the solution bins of the scaffolded puzzles with a single cargo invocation.
+    /// Build errors are left to the runs of the individual solutions to report.
+    pub fn build_solutions(ids: &[PuzzleId], is_release: bool) {
+        let mut args = vec!["build".to_string(), "--quiet".into()];
+        for id in ids.iter().filter(|id| is_scaffolded(**id)) {
+            args.push("--bin".into());
+            args.push(id.to_string());
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        if args.len() == 2 {
+            return;
+        }
 
         if is_release {
-            args.push("--release");
+            args.push("--release".into());
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        let _ = Command::new("cargo")
+            .args(&args)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status();
+    }
+
+    /// Run the solution bin for a given puzzle without printing its output.
+    /// Returns `None` for puzzles that have not been scaffolded yet.
+    pub fn capture_solution(
+        id: PuzzleId,
+        is_release: bool,
+    ) -> Result<Option<SolutionOutput>, Error> {
+        if !is_scaffolded(id) {
+            return Ok(None);
         }
 
+        let output = Command::new("cargo")
+            .args(cargo_args(id, RunOptions::default(), is_release))
+            .output()?;
+
+        let lines = |bytes: &[u8]| {
+            String::from_utf8_lossy(bytes)
+                .lines()
+                .map(ToString::to_string)
+                .collect()
+        };
+
+        Ok(Some(SolutionOutput {
+            stdout: lines(&output.stdout),
+            stderr: lines(&output.stderr),
+            success: output.status.success(),
+        }))
+    }
+
+    /// Run the solution bin for a given puzzle
+    pub fn run_solution(
+        id: PuzzleId,
+        options: RunOptions,
+        is_release: bool,
+    ) -> Result<Vec<String>, Error> {
+        // skip command invocation for days that have not been scaffolded yet.
+        if !is_scaffolded(id) {
+            return Ok(vec![]);
+        }
+
+        let args = cargo_args(id, options, is_release);
+
         // spawn child command with piped stdout/stderr.
         // forward output to stdout/stderr while grabbing stdout lines.
 
@@ -148,22 +418,32 @@ pub mod child_commands {
                     return None;
                 };
 
-                let part = l.split(':').next()?;
+                let part = l.split(':').next()?.rsplit("Part ").next()?.parse().ok()?;
                 Some((part, timing_str, nanos))
             })
             .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
-
+                *timings.part_mut(part) = Some(timing_str.into());
                 timings.total_nanos += nanos;
             });
 
         timings
     }
 
+    /// Parses the duration of every part in the output of a solution, whether it was benched or run once.
+    pub fn parse_part_times(output: &[String]) -> Vec<(Part, f64)> {
+        output
+            .iter()
+            .filter_map(|l| {
+                let part = l.split(':').next()?.rsplit("Part ").next()?.parse().ok()?;
+                if !l.ends_with(')') {
+                    return None;
+                }
+                let (_, nanos) = parse_time(l)?;
+                Some((part, nanos))
+            })
+            .collect()
+    }
+
     fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
         s.split(postfix).next()?.parse().ok()
     }
@@ -177,7 +457,8 @@ pub mod child_commands {
             .next_back()?
             .split('@')
             .next()?
-            .trim();
+            .trim()
+            .trim_end_matches(')');
 
         let parsed_timing = match str_timing {
             s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
@@ -205,7 +486,8 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{parse_exec_time, parse_part_times};
+        use crate::template::Part;
 
         use crate::day;
 
@@ -253,5 +535,57 @@ pub mod child_commands {
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[test]
+        fn parses_part_times() {
+            let res = parse_part_times(&[
+                "Part 1: 42 (166.0ns)".into(),
+                "Part 2: (1, 2) (1.5ms @ 200 samples)".into(),
+                "Part 2 is not a result".into(),
+            ]);
+            assert_eq!(res.len(), 2);
+            assert_eq!(res[0].0, Part::One);
+            assert_approx_eq!(res[0].1, 166_f64);
+            assert_eq!(res[1].0, Part::Two);
+            assert_approx_eq!(res[1].1, 1_500_000_f64);
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Outcome, Summary, child_commands::SolutionOutput};
+    use crate::day;
+
+    fn output(stdout: &[&str], success: bool) -> SolutionOutput {
+        SolutionOutput {
+            stdout: stdout.iter().map(ToString::to_string).collect(),
+            stderr: vec![],
+            success,
+        }
+    }
+
+    #[test]
+    fn classifies_outcomes() {
+        let passed = output(&["Part 1: 1 (1.0µs)", "Part 2: 2 (1.0µs)"], true);
+        let partial = output(&["Part 1: 1 (1.0µs)", "Part 2: ✖"], true);
+        assert_eq!(Outcome::of(Some(&passed)), Outcome::Passed);
+        assert_eq!(Outcome::of(Some(&partial)), Outcome::Unsolved);
+        assert_eq!(Outcome::of(Some(&output(&[], true))), Outcome::Unsolved);
+        assert_eq!(Outcome::of(Some(&output(&[], false))), Outcome::Failed);
+        assert_eq!(Outcome::of(None), Outcome::Unsolved);
+    }
+
+    #[test]
+    fn summarizes_outcomes() {
+        let mut summary = Summary::default();
+        summary.add(day!(1), Outcome::Passed);
+        summary.add(day!(2), Outcome::Unsolved);
+        assert!(!summary.has_failures());
+        summary.add(day!(3), Outcome::Failed);
+        assert!(summary.has_failures());
+        assert_eq!(summary.passed, 1);
+        assert_eq!(summary.failed, vec![day!(3)]);
+        assert_eq!(summary.unsolved, 1);
     }
 }
@@ -4,21 +4,74 @@ use std::hint::black_box;
 use std::io::{Write, stdout};
 use std::process::Output;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, process};
+
+use clap::Parser;
 
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::{ANSI_ITALIC, ANSI_RESET, Part, PuzzleId, aoc_cli, config::config};
+
+/// Options that the `advent_of_code` command-line passes on to solution binaries.
+#[derive(Parser, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[command(about = "Runs the solution of a single puzzle.")]
+pub struct RunOptions {
+    /// Bench every part instead of running it once.
+    #[arg(long)]
+    pub time: bool,
+    /// Submit the answer of this part via aoc-cli.
+    #[arg(long, value_name = "PART")]
+    pub submit: Option<Part>,
+    /// Run every part against its example instead of the input.
+    #[arg(long, conflicts_with = "submit")]
+    pub example: bool,
+}
+
+impl RunOptions {
+    /// Parses the arguments of the solution binary, exits with a usage error if they are invalid.
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::parse()
+    }
+
+    /// Arguments that [`RunOptions::from_env`] parses back in the solution binary.
+    #[must_use]
+    pub fn to_args(self) -> Vec<String> {
+        let mut args = vec![];
+        if self.time {
+            args.push("--time".into());
+        }
+        if let Some(part) = self.submit {
+            args.push("--submit".into());
+            args.push(part.to_string());
+        }
+        if self.example {
+            args.push("--example".into());
+        }
+        args
+    }
+}
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+pub fn run_part<I: Copy, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    id: PuzzleId,
+    part: Part,
+    options: &RunOptions,
+) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, duration, samples) = run_timed(func, input, options.time, |result| {
+        print_result(result, &part_str, "")
+    });
 
     print_result(&result, &part_str, &format_duration(&duration, samples));
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    if let Some(result) = result
+        && options.submit == Some(part)
+        && let Err(e) = submit_result(result, id, part)
+    {
+        eprintln!("failed to submit result: {e}");
+        process::exit(1);
     }
 }
 
@@ -28,6 +81,7 @@ pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, da
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
+    is_timed: bool,
     hook: impl Fn(&T),
 ) -> (T, Duration, u128) {
     let timer = Instant::now();
@@ -41,7 +95,7 @@ fn run_timed<I: Copy, T>(
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
+    let run = if is_timed {
         bench(func, input, &base_time)
     } else {
         (base_time, 1)
@@ -56,8 +110,9 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 50000);
+    let bench = &config().bench;
+    let bench_iterations = (bench.target_duration.as_nanos() / cmp::max(base_time.as_nanos(), 10))
+        .clamp(bench.min_samples, bench.max_samples);
 
     let mut count = 0;
     let timer = Instant::now();
@@ -120,36 +175,12 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+/// Try to submit one part of the solution, requires aoc-cli to be installed.
 fn submit_result<T: Display>(
     result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
-
-    if !args.contains(&"--submit".into()) {
-        return None;
-    }
-
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
-    }
-
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
-
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
-    };
-
-    if part_submit != part {
-        return None;
-    }
-
+    id: PuzzleId,
+    part: Part,
+) -> Result<Output, aoc_cli::AocCommandError> {
     if aoc_cli::check().is_err() {
         eprintln!(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
@@ -158,5 +189,5 @@ fn submit_result<T: Display>(
     }
 
     println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    aoc_cli::submit(id, part, &result.to_string())
 }
@@ -0,0 +1,290 @@
+/// Selects the days that batch commands like `all` and `time` run.
+use std::{
+    collections::HashSet, error::Error, fmt::Display, ops::RangeInclusive, path::Path,
+    process::Command, str::FromStr,
+};
+
+use clap::Args;
+
+use crate::template::{
+    AllDays, Day, Part, PuzzleId, config::config, event_length, stars::read_stars, timings::Timings,
+};
+
+/// An error which can be returned when parsing or resolving a [`DaySelection`].
+#[derive(Debug, PartialEq, Eq)]
+pub struct SelectionError(String);
+
+impl Error for SelectionError {}
+
+impl Display for SelectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// A list of days and day ranges, e.g. `1-5,8`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayRanges(Vec<RangeInclusive<u8>>);
+
+impl DayRanges {
+    fn contains(&self, day: Day) -> bool {
+        self.0.iter().any(|range| range.contains(&day.into_inner()))
+    }
+
+    fn last_day(&self) -> u8 {
+        self.0.iter().map(|range| *range.end()).max().unwrap_or(0)
+    }
+}
+
+impl FromStr for DayRanges {
+    type Err = SelectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // the length of the event is only known once the year is, `resolve` checks the last day.
+        let parse_day = |day: &str| {
+            day.trim()
+                .parse::<u8>()
+                .ok()
+                .filter(|day| *day > 0)
+                .ok_or_else(|| SelectionError(format!("expected a day number, got `{day}`.")))
+        };
+
+        s.split(',')
+            .map(|part| {
+                let (start, end) = match part.split_once('-') {
+                    Some((start, end)) => (parse_day(start)?, parse_day(end)?),
+                    None => (parse_day(part)?, parse_day(part)?),
+                };
+
+                if start > end {
+                    return Err(SelectionError(format!(
+                        "expected range `{part}` to be ascending."
+                    )));
+                }
+
+                Ok(start..=end)
+            })
+            .collect::<Result<_, _>>()
+            .map(DayRanges)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Command-line options that select a subset of the days of an event.
+///
+/// Every given option narrows the selection down further, `--slowest` is applied last.
+#[derive(Args, Clone, Debug, Default, PartialEq, Eq)]
+pub struct DaySelection {
+    /// Days to run, e.g. `8` or `1-5,8`. Defaults to every day of the event.
+    #[arg(value_name = "DAYS")]
+    pub days: Option<DayRanges>,
+    /// Only days without both stars in the ⭐️ progress table of the readme.
+    #[arg(long)]
+    pub unsolved: bool,
+    /// Only days without stored timings for both parts.
+    #[arg(long)]
+    pub unbenched: bool,
+    /// Only days whose solution or data files changed since a git revision.
+    #[arg(long, value_name = "REV")]
+    pub changed_since: Option<String>,
+    /// Only the N slowest days according to stored timings.
+    #[arg(long, value_name = "N")]
+    pub slowest: Option<usize>,
+}
+
+impl DaySelection {
+    /// Whether no days or filters were given, i.e. the selection contains every day.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Resolves the selection to days of the event in `year`.
+    pub fn resolve(&self, year: u16) -> Result<HashSet<Day>, SelectionError> {
+        if let Some(days) = &self.days
+            && days.last_day() > event_length(year)
+        {
+            return Err(SelectionError(format!(
+                "the {year} event has {} days, got day {}.",
+                event_length(year),
+                days.last_day()
+            )));
+        }
+
+        let mut days: Vec<Day> = AllDays::for_year(year)
+            .filter(|day| self.days.as_ref().is_none_or(|days| days.contains(*day)))
+            .collect();
+
+        if self.unsolved {
+            let stars = read_stars(year);
+            days.retain(|day| stars.get(day).copied().unwrap_or(0) < 2);
+        }
+
+        let timings = Timings::read_from_file(year);
+
+        if self.unbenched {
+            days.retain(|day| !timings.is_day_complete(*day));
+        }
+
+        if let Some(rev) = &self.changed_since {
+            let changed = changed_files(rev)?;
+            // every solution depends on the library, a change to it keeps all days.
+            if !changed.iter().any(|path| is_library_file(path)) {
+                days.retain(|day| {
+                    let id = PuzzleId::new(year, *day).unwrap();
+                    changed.iter().any(|path| is_day_file(path, id))
+                });
+            }
+        }
+
+        if let Some(n) = self.slowest {
+            // days without stored timings have no known speed and are never among the slowest.
+            let mut timed: Vec<(Day, f64)> = days
+                .iter()
+                .filter_map(|day| {
+                    let timing = timings.data.iter().find(|t| t.day == *day)?;
+                    Some((*day, timing.total_nanos))
+                })
+                .collect();
+            timed.sort_unstable_by(|a, b| b.1.total_cmp(&a.1));
+            days = timed.into_iter().take(n).map(|(day, _)| day).collect();
+        }
+
+        Ok(days.into_iter().collect())
+    }
+}
+
+/// Paths of tracked files that changed since `rev`, including uncommitted changes, and of untracked files.
+fn changed_files(rev: &str) -> Result<Vec<String>, SelectionError> {
+    let git = |args: &[&str]| {
+        let output = Command::new("git")
+            .args(args)
+            .output()
+            .map_err(|_| SelectionError("git is not present in environment.".into()))?;
+
+        if !output.status.success() {
+            return Err(SelectionError(format!(
+                "git {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>())
+    };
+
+    let mut files = git(&["diff", "--name-only", rev, "--"])?;
+    files.extend(git(&["ls-files", "--others", "--exclude-standard"])?);
+    Ok(files)
+}
+
+/// Whether `path` is the solution of the puzzle or one of its data files, e.g. `data/2025/examples/08-2.txt`.
+fn is_day_file(path: &str, id: PuzzleId) -> bool {
+    if path == config().bin_path(id) {
+        return true;
+    }
+
+    let path = Path::new(path);
+    let day = id.day().to_string();
+    path.starts_with(config().data_dir(id.year()))
+        && path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .is_some_and(|stem| {
+                stem == day
+                    || stem
+                        .strip_prefix(&day)
+                        .and_then(|rest| rest.strip_prefix('-'))
+                        .is_some_and(|part| part.parse::<Part>().is_ok())
+            })
+}
+
+/// Whether `path` is part of the library the solutions share, i.e. `src/lib.rs` and the modules next to it.
+fn is_library_file(path: &str) -> bool {
+    let path = Path::new(path);
+    path.parent() == Some(Path::new("src"))
+        && path.extension().is_some_and(|ext| ext == "rs")
+        && path.file_name().is_some_and(|name| name != "main.rs")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{DayRanges, DaySelection, is_day_file, is_library_file};
+    use crate::{day, template::PuzzleId};
+
+    #[test]
+    fn parses_day_ranges() {
+        let ranges: DayRanges = "1-5, 8".parse().unwrap();
+        assert_eq!(ranges, DayRanges(vec![1..=5, 8..=8]));
+        assert!(ranges.contains(day!(3)));
+        assert!(!ranges.contains(day!(7)));
+        assert_eq!(ranges.last_day(), 8);
+    }
+
+    #[test]
+    fn rejects_invalid_ranges() {
+        assert!("".parse::<DayRanges>().is_err());
+        assert!("0".parse::<DayRanges>().is_err());
+        assert!("5-1".parse::<DayRanges>().is_err());
+        assert!("1,,2".parse::<DayRanges>().is_err());
+    }
+
+    #[test]
+    fn resolves_day_ranges() {
+        let selection = DaySelection {
+            days: Some("1-3,12".parse().unwrap()),
+            ..DaySelection::default()
+        };
+        let mut days: Vec<_> = selection.resolve(2025).unwrap().into_iter().collect();
+        days.sort_unstable();
+        assert_eq!(days, vec![day!(1), day!(2), day!(3), day!(12)]);
+
+        let selection = DaySelection {
+            days: Some("13".parse().unwrap()),
+            ..DaySelection::default()
+        };
+        assert!(selection.resolve(2025).is_err());
+        assert!(selection.resolve(2024).is_ok());
+
+        let selection = DaySelection {
+            days: Some("1-26".parse().unwrap()),
+            ..DaySelection::default()
+        };
+        assert_eq!(
+            selection.resolve(2024).unwrap_err().to_string(),
+            "the 2024 event has 25 days, got day 26."
+        );
+    }
+
+    #[test]
+    fn matches_day_files() {
+        let id = PuzzleId::new(2025, day!(8)).unwrap();
+        assert!(is_day_file("src/bin/2025_08.rs", id));
+        assert!(is_day_file("data/2025/examples/08-2.txt", id));
+        assert!(!is_day_file("data/2024/examples/08.txt", id));
+        assert!(!is_day_file("src/bin/2025_09.rs", id));
+        assert!(!is_day_file("src/lib.rs", id));
+
+        let id = PuzzleId::new(2025, day!(1)).unwrap();
+        assert!(is_day_file("data/2025/inputs/01.txt", id));
+        assert!(is_day_file("data/2025/puzzles/01.md", id));
+        assert!(is_day_file("data/2025/examples/01-1.txt", id));
+        assert!(!is_day_file("data/2025/inputs/10.txt", id));
+        assert!(!is_day_file("data/2025/examples/011.txt", id));
+        assert!(!is_day_file("data/2025/examples/01-3.txt", id));
+    }
+
+    #[test]
+    fn matches_library_files() {
+        assert!(is_library_file("src/lib.rs"));
+        assert!(is_library_file("src/grid.rs"));
+        assert!(!is_library_file("src/main.rs"));
+        assert!(!is_library_file("src/bin/2025_08.rs"));
+        assert!(!is_library_file("src/template/runner.rs"));
+    }
+}
@@ -0,0 +1,238 @@
+/// Manages the advent of code session token that aoc-cli and the leaderboard use to authenticate.
+use std::{
+    env,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    process::{Command, Stdio},
+};
+
+use crate::template::config::user_config_dir;
+
+static SESSION_FILE_NAME: &str = "adventofcode.session";
+static SESSION_ENV: &str = "ADVENT_OF_CODE_SESSION";
+static SETTINGS_URL: &str = "https://adventofcode.com/settings";
+static USER_AGENT: &str = "github.com/stevenwcarter/aoc-2025 via curl";
+
+#[derive(Debug)]
+pub enum SessionError {
+    Missing,
+    Expired,
+    CurlNotFound,
+    RequestFailed(String),
+}
+
+impl Display for SessionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SessionError::Missing => {
+                write!(
+                    f,
+                    "no session token found. Run `cargo aoc-login` to store one."
+                )
+            }
+            SessionError::Expired => write!(
+                f,
+                "session expired. Run `cargo aoc-login` with a fresh session cookie."
+            ),
+            SessionError::CurlNotFound => write!(f, "curl is not present in environment."),
+            SessionError::RequestFailed(reason) => {
+                write!(f, "could not reach adventofcode.com: {reason}")
+            }
+        }
+    }
+}
+
+/// Location of the session file in the user config directory, i.e. `$XDG_CONFIG_HOME/adventofcode.session`.
+/// aoc-cli looks in the same location.
+#[must_use]
+pub fn get_session_path() -> Option<PathBuf> {
+    user_config_dir().map(|dir| dir.join(SESSION_FILE_NAME))
+}
+
+/// Location of the session file used by older aoc-cli versions, i.e. `~/.adventofcode.session`.
+fn get_legacy_session_path() -> Option<PathBuf> {
+    env::var_os("HOME").map(|home| PathBuf::from(home).join(format!(".{SESSION_FILE_NAME}")))
+}
+
+/// Accepts both the bare cookie value and a pasted `session=<value>` pair.
+pub fn normalize_token(token: &str) -> String {
+    let token = token.trim();
+    token.strip_prefix("session=").unwrap_or(token).to_string()
+}
+
+/// Returns the path of the session file that aoc-cli should use, if one exists.
+#[must_use]
+pub fn find_session_file() -> Option<PathBuf> {
+    [get_session_path(), get_legacy_session_path()]
+        .into_iter()
+        .flatten()
+        .find(|path| path.is_file())
+}
+
+/// Loads the session token from the environment or the session file.
+pub fn load() -> Result<String, SessionError> {
+    if let Ok(token) = env::var(SESSION_ENV) {
+        return Ok(normalize_token(&token));
+    }
+
+    let path = find_session_file().ok_or(SessionError::Missing)?;
+    let token = fs::read_to_string(path).map_err(|_| SessionError::Missing)?;
+    let token = normalize_token(&token);
+
+    if token.is_empty() {
+        Err(SessionError::Missing)
+    } else {
+        Ok(token)
+    }
+}
+
+/// Stores the session token in the user config directory, readable only by the current user.
+pub fn store(token: &str) -> Result<PathBuf, io::Error> {
+    let path = get_session_path().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            "could not determine user config directory.",
+        )
+    })?;
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(0o600);
+        // `mode` only applies to new files, tighten permissions of an existing file as well.
+        if path.exists() {
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o600))?;
+        }
+    }
+
+    let mut file = options.open(&path)?;
+    file.write_all(normalize_token(token).as_bytes())?;
+    Ok(path)
+}
+
+/// Extracts the user name from the header of an advent of code page, i.e. `<div class="user">name ...`.
+fn parse_user_name(html: &str) -> Option<String> {
+    let (_, rest) = html.split_once("<div class=\"user\">")?;
+    let name = rest.split('<').next()?.trim();
+    if name.is_empty() {
+        None
+    } else {
+        Some(name.to_string())
+    }
+}
+
+/// A curl config passing the session cookie, so the token does not show up in the process list.
+fn cookie_config(token: &str) -> String {
+    let escaped = token.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("cookie = \"session={escaped}\"\n")
+}
+
+/// Requests `url` with the session cookie and returns the HTTP status code and response body.
+fn curl(url: &str, token: &str) -> Result<(String, String), SessionError> {
+    let mut child = Command::new("curl")
+        .args([
+            "--silent",
+            "--show-error",
+            "--user-agent",
+            USER_AGENT,
+            // read the cookie from the config on stdin instead of the command line.
+            "--config",
+            "-",
+            "--write-out",
+            "\n%{http_code}",
+            url,
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|_| SessionError::CurlNotFound)?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(cookie_config(token).as_bytes())
+            .map_err(|e| SessionError::RequestFailed(e.to_string()))?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| SessionError::RequestFailed(e.to_string()))?;
+
+    if !output.status.success() {
+        return Err(SessionError::RequestFailed(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    let body = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = body.rsplit_once('\n').unwrap_or(("", &body));
+    Ok((status.trim().to_string(), body.to_string()))
+}
+
+fn check_status(status: &str) -> Result<(), SessionError> {
+    match status {
+        "200" => Ok(()),
+        // pages that require a login redirect to the front page or respond with an error.
+        "302" | "400" | "401" | "403" => Err(SessionError::Expired),
+        status => Err(SessionError::RequestFailed(format!(
+            "unexpected HTTP status {status}."
+        ))),
+    }
+}
+
+/// Fetches an authenticated advent of code page using the stored session token.
+pub fn fetch(url: &str) -> Result<String, SessionError> {
+    let (status, body) = curl(url, &load()?)?;
+    check_status(&status)?;
+    Ok(body)
+}
+
+/// Validates a session token against the advent of code website and returns the name of its user.
+pub fn whoami(token: &str) -> Result<String, SessionError> {
+    let (status, html) = curl(SETTINGS_URL, token)?;
+    check_status(&status)?;
+    parse_user_name(&html).ok_or(SessionError::Expired)
+}
+
+/// Loads and validates the stored session token.
+pub fn check() -> Result<String, SessionError> {
+    whoami(&load()?)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{cookie_config, normalize_token, parse_user_name};
+
+    #[test]
+    fn normalizes_tokens() {
+        assert_eq!(normalize_token(" 53616c74 \n"), "53616c74");
+        assert_eq!(normalize_token("session=53616c74"), "53616c74");
+    }
+
+    #[test]
+    fn passes_cookie_in_config() {
+        assert_eq!(cookie_config("53616c74"), "cookie = \"session=53616c74\"\n");
+        assert_eq!(
+            cookie_config(r#"a"b\c"#),
+            "cookie = \"session=a\\\"b\\\\c\"\n"
+        );
+    }
+
+    #[test]
+    fn parses_user_name() {
+        let html = r#"<header><div class="user">Jane Doe <span class="star-count">24*</span></div></header>"#;
+        assert_eq!(parse_user_name(html), Some("Jane Doe".into()));
+        assert_eq!(parse_user_name("<header></header>"), None);
+    }
+}
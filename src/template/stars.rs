@@ -0,0 +1,71 @@
+/// Reads the ⭐️ progress table that the readme-stars workflow maintains in the readme.
+use std::{collections::HashMap, fs};
+
+use crate::template::{Day, config::config};
+
+pub static MARKER: &str = "<!--- advent_readme_stars table --->";
+
+/// Counts the stars per day in the progress table of `readme`.
+/// Days that are missing from the table have no stars.
+pub fn parse_stars(readme: &str) -> HashMap<Day, u8> {
+    let mut sections = readme.split(MARKER);
+    let Some(table) = sections.nth(1) else {
+        return HashMap::new();
+    };
+
+    table
+        .lines()
+        .filter_map(|line| {
+            let day = line
+                .strip_prefix("| [Day ")?
+                .split(']')
+                .next()?
+                .parse()
+                .ok()
+                .and_then(Day::new)?;
+            Some((day, line.matches('⭐').count() as u8))
+        })
+        .collect()
+}
+
+/// Reads the stars of the event in `year` from its readme.
+pub fn read_stars(year: u16) -> HashMap<Day, u8> {
+    fs::read_to_string(config().readme_path(year))
+        .map(|readme| parse_stars(&readme))
+        .unwrap_or_default()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{MARKER, parse_stars};
+    use crate::day;
+
+    #[test]
+    fn counts_stars_per_day() {
+        let readme = [
+            "# readme",
+            MARKER,
+            "## 2025 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2025/day/1) | ⭐ | ⭐ |",
+            "| [Day 2](https://adventofcode.com/2025/day/2) | ⭐ |   |",
+            MARKER,
+            "| [Day 3](./src/bin/2025_03.rs) | `1ms` | `2ms` |",
+        ]
+        .join("\n");
+
+        let stars = parse_stars(&readme);
+        assert_eq!(stars.get(&day!(1)), Some(&2));
+        assert_eq!(stars.get(&day!(2)), Some(&1));
+        assert_eq!(stars.get(&day!(3)), None);
+    }
+
+    #[test]
+    fn handles_missing_table() {
+        assert!(parse_stars("# readme").is_empty());
+    }
+}
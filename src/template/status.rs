@@ -0,0 +1,230 @@
+/// Collects the lifecycle of a day, from scaffolding to solving and benching it.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    path::Path,
+    process::{Command, Stdio},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    Day, Part, PuzzleId,
+    config::config,
+    puzzle::extract_answers,
+    timings::{Timing, Timings},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileStatus {
+    Missing,
+    Empty,
+    Present,
+}
+
+impl FileStatus {
+    fn of(path: &str) -> Self {
+        match fs::read_to_string(path) {
+            Ok(contents) if contents.trim().is_empty() => FileStatus::Empty,
+            Ok(_) => FileStatus::Present,
+            Err(_) => FileStatus::Missing,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            FileStatus::Missing => "missing",
+            FileStatus::Empty => "empty",
+            FileStatus::Present => "present",
+        }
+    }
+}
+
+impl Display for FileStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            FileStatus::Missing => "-",
+            FileStatus::Empty => "empty",
+            FileStatus::Present => "✓",
+        })
+    }
+}
+
+/// How far a part got, judging by the files in the repository.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    Unsolved,
+    /// The part has a star in the ⭐️ progress table of the readme.
+    Submitted,
+    /// The accepted answer is part of the downloaded puzzle description.
+    Verified,
+}
+
+impl PartStatus {
+    /// `answers` are the accepted answers of the puzzle description, `stars` the stars of the progress table.
+    fn of(part: Part, answers: usize, stars: u8) -> Self {
+        let index = part.into_inner();
+        if answers >= usize::from(index) {
+            PartStatus::Verified
+        } else if stars >= index {
+            PartStatus::Submitted
+        } else {
+            PartStatus::Unsolved
+        }
+    }
+}
+
+impl Display for PartStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            PartStatus::Unsolved => "unsolved",
+            PartStatus::Submitted => "submitted",
+            PartStatus::Verified => "verified",
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DayStatus {
+    pub day: Day,
+    pub scaffolded: bool,
+    pub input: FileStatus,
+    /// The example each part runs against, its part-specific one if present.
+    pub examples: [FileStatus; 2],
+    pub puzzle: FileStatus,
+    /// Whether the tests of the solution pass, `None` if they were not run.
+    pub examples_pass: Option<bool>,
+    pub parts: [PartStatus; 2],
+    pub timing: Option<Timing>,
+}
+
+/// Runs the tests of a solution binary, which check its examples.
+fn run_example_tests(id: PuzzleId) -> bool {
+    Command::new("cargo")
+        .args(["test", "--quiet", "--bin", &id.to_string()])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+impl DayStatus {
+    pub fn collect(
+        id: PuzzleId,
+        stars: &HashMap<Day, u8>,
+        timings: &Timings,
+        run_tests: bool,
+    ) -> Self {
+        let config = config();
+        let scaffolded = Path::new(&config.bin_path(id)).exists();
+
+        let answers = fs::read_to_string(config.puzzle_path(id))
+            .map(|puzzle| extract_answers(&puzzle).len())
+            .unwrap_or(0);
+        let stars = stars.get(&id.day()).copied().unwrap_or(0);
+
+        DayStatus {
+            day: id.day(),
+            scaffolded,
+            input: FileStatus::of(&config.input_path(id)),
+            examples: Part::ALL.map(|part| FileStatus::of(&config.resolve_example_path(id, part))),
+            puzzle: FileStatus::of(&config.puzzle_path(id)),
+            examples_pass: (run_tests && scaffolded).then(|| run_example_tests(id)),
+            parts: Part::ALL.map(|part| PartStatus::of(part, answers, stars)),
+            timing: timings.data.iter().find(|t| t.day == id.day()).cloned(),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&DayStatus> for JsonValue {
+    fn from(value: &DayStatus) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "day".into(),
+            JsonValue::Number(f64::from(value.day.into_inner())),
+        );
+        map.insert("scaffolded".into(), JsonValue::Boolean(value.scaffolded));
+
+        for (key, status) in [("input", value.input), ("puzzle", value.puzzle)] {
+            map.insert(key.into(), JsonValue::String(status.as_str().into()));
+        }
+
+        map.insert(
+            "examples_pass".into(),
+            value
+                .examples_pass
+                .map_or(JsonValue::Null, JsonValue::Boolean),
+        );
+
+        for ((part, status), example) in Part::ALL.iter().zip(value.parts).zip(value.examples) {
+            let mut part_map: HashMap<String, JsonValue> = HashMap::new();
+            part_map.insert("status".into(), JsonValue::String(status.to_string()));
+            part_map.insert("example".into(), JsonValue::String(example.as_str().into()));
+            part_map.insert(
+                "benchmark".into(),
+                value
+                    .timing
+                    .as_ref()
+                    .and_then(|t| t.part(*part))
+                    .map_or(JsonValue::Null, |t| JsonValue::String(t.into())),
+            );
+            map.insert(format!("part_{part}"), JsonValue::Object(part_map));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{DayStatus, FileStatus, PartStatus};
+    use crate::{day, template::Part, template::timings::Timing};
+    use std::collections::HashMap;
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn prefers_verified_answers_over_stars() {
+        assert_eq!(PartStatus::of(Part::One, 0, 0), PartStatus::Unsolved);
+        assert_eq!(PartStatus::of(Part::One, 0, 1), PartStatus::Submitted);
+        assert_eq!(PartStatus::of(Part::One, 1, 2), PartStatus::Verified);
+        assert_eq!(PartStatus::of(Part::Two, 1, 2), PartStatus::Submitted);
+        assert_eq!(PartStatus::of(Part::Two, 1, 1), PartStatus::Unsolved);
+    }
+
+    #[test]
+    fn serializes_to_json() {
+        let status = DayStatus {
+            day: day!(8),
+            scaffolded: true,
+            input: FileStatus::Present,
+            examples: [FileStatus::Present, FileStatus::Empty],
+            puzzle: FileStatus::Missing,
+            examples_pass: None,
+            parts: [PartStatus::Verified, PartStatus::Unsolved],
+            timing: Some(Timing {
+                day: day!(8),
+                part_1: Some("1.8ms".into()),
+                part_2: None,
+                total_nanos: 1.8e6,
+            }),
+        };
+
+        let json = JsonValue::from(&status);
+        let map = json.get::<HashMap<String, JsonValue>>().unwrap();
+        assert_eq!(map["day"], JsonValue::Number(8.0));
+        assert_eq!(map["examples_pass"], JsonValue::Null);
+
+        let part_1 = map["part_1"].get::<HashMap<String, JsonValue>>().unwrap();
+        assert_eq!(part_1["status"], JsonValue::String("verified".into()));
+        assert_eq!(part_1["benchmark"], JsonValue::String("1.8ms".into()));
+        assert_eq!(part_1["example"], JsonValue::String("present".into()));
+
+        let part_2 = map["part_2"].get::<HashMap<String, JsonValue>>().unwrap();
+        assert_eq!(part_2["example"], JsonValue::String("empty".into()));
+    }
+}
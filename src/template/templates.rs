@@ -0,0 +1,454 @@
+/// Loads and renders the module templates of `cargo scaffold`.
+use std::{
+    collections::BTreeSet, env, error::Error, fmt::Display, fs, path::PathBuf, process::Command,
+};
+
+use crate::template::{
+    PuzzleId,
+    answers::{ExampleAnswers, to_expected_value},
+    config::{config, user_config_dir},
+};
+
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+macro_rules! builtin_templates {
+    ($($name:literal),* $(,)?) => {
+        [$((
+            $name,
+            include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/templates/", $name, ".txt")),
+        )),*]
+    };
+}
+
+/// Fallbacks for when the templates directory is missing a template.
+const BUILTIN_TEMPLATES: [(&str, &str); 5] =
+    builtin_templates!("default", "lines", "grid", "graph", "shared-parse");
+
+/// An error which can be returned when loading or rendering a template.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TemplateError(String);
+
+impl Error for TemplateError {}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Values of the `%NAME%` placeholders a template may contain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Placeholders(Vec<(&'static str, String)>);
+
+impl Placeholders {
+    /// `title` is the name of the puzzle, e.g. `Playground`, if it was downloaded.
+    pub fn new(
+        id: PuzzleId,
+        title: Option<String>,
+        answers: &ExampleAnswers,
+        author: String,
+    ) -> Self {
+        let day = id.day().into_inner();
+        Placeholders(vec![
+            // e.g. `8`, as expected by `solution!`.
+            ("DAY_NUMBER", day.to_string()),
+            // e.g. `08`.
+            ("DAY", id.day().to_string()),
+            ("YEAR", id.year().to_string()),
+            // e.g. `Day 8: Playground`, or `Day 8` before the puzzle is downloaded.
+            (
+                "TITLE",
+                title.map_or_else(
+                    || format!("Day {day}"),
+                    |title| format!("Day {day}: {title}"),
+                ),
+            ),
+            // the day the puzzle unlocks, e.g. `2025-12-08`.
+            ("DATE", format!("{}-12-{}", id.year(), id.day())),
+            ("AUTHOR", author),
+            // expected results of the example tests, e.g. `Some(40)`, or `None` if not known yet.
+            (
+                "PART_ONE_ANSWER",
+                to_expected_value(answers.part_1.as_deref()),
+            ),
+            (
+                "PART_TWO_ANSWER",
+                to_expected_value(answers.part_2.as_deref()),
+            ),
+        ])
+    }
+
+    fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Finds the names of all `%NAME%` placeholders in `template`.
+fn placeholders(template: &str) -> Vec<(usize, &str)> {
+    let mut found = vec![];
+    let mut rest = 0;
+
+    while let Some(start) = template[rest..].find('%').map(|i| rest + i) {
+        let name_len = template[start + 1..]
+            .find(|c: char| !(c.is_ascii_uppercase() || c == '_'))
+            .unwrap_or(template.len() - start - 1);
+        let end = start + 1 + name_len;
+
+        if name_len > 0 && template[end..].starts_with('%') {
+            found.push((start, &template[start + 1..end]));
+            rest = end + 1;
+        } else {
+            rest = start + 1;
+        }
+    }
+
+    found
+}
+
+/// Substitutes the placeholders of `template`. Fails on unknown placeholders instead of leaving them in.
+pub fn render(template: &str, values: &Placeholders) -> Result<String, TemplateError> {
+    let found = placeholders(template);
+
+    let unknown: BTreeSet<&str> = found
+        .iter()
+        .map(|(_, name)| *name)
+        .filter(|name| values.get(name).is_none())
+        .collect();
+
+    if !unknown.is_empty() {
+        let unknown: Vec<String> = unknown.iter().map(|name| format!("%{name}%")).collect();
+        let known: Vec<String> = values.0.iter().map(|(key, _)| format!("%{key}%")).collect();
+        return Err(TemplateError(format!(
+            "unknown placeholders {}, expected one of {}.",
+            unknown.join(", "),
+            known.join(", ")
+        )));
+    }
+
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = 0;
+    for (start, name) in found {
+        rendered.push_str(&template[rest..start]);
+        rendered.push_str(values.get(name).unwrap());
+        rest = start + name.len() + 2;
+    }
+    rendered.push_str(&template[rest..]);
+
+    Ok(rendered)
+}
+
+/// Where a template was loaded from, in the order templates are looked up.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TemplateSource {
+    /// The templates directory of the repository, see `paths.templates`.
+    Repository(PathBuf),
+    /// The templates directory of the user, shared by all repositories.
+    User(PathBuf),
+    Builtin,
+}
+
+impl Display for TemplateSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateSource::Repository(path) => write!(f, "\"{}\"", path.display()),
+            TemplateSource::User(path) => write!(f, "\"{}\" (user)", path.display()),
+            TemplateSource::Builtin => f.write_str("built-in"),
+        }
+    }
+}
+
+/// A template and where it was loaded from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Template {
+    pub name: String,
+    pub source: TemplateSource,
+    pub contents: String,
+}
+
+/// Templates of the user, i.e. `~/.config/advent_of_code/templates`.
+fn user_templates_dir() -> Option<PathBuf> {
+    user_config_dir().map(|dir| dir.join("advent_of_code").join("templates"))
+}
+
+/// A directory containing templates, and the source of the templates loaded from it.
+type TemplateDir = (PathBuf, fn(PathBuf) -> TemplateSource);
+
+/// Directories to look up templates in, the first one containing a template wins.
+fn template_dirs() -> Vec<TemplateDir> {
+    let mut dirs: Vec<TemplateDir> = vec![(
+        PathBuf::from(&config().paths.templates),
+        TemplateSource::Repository,
+    )];
+    if let Some(dir) = user_templates_dir() {
+        dirs.push((dir, TemplateSource::User));
+    }
+    dirs
+}
+
+/// Names of the templates in the template directories and the builtin ones.
+pub fn available() -> Vec<String> {
+    let mut names: BTreeSet<String> = BUILTIN_TEMPLATES
+        .iter()
+        .map(|(name, _)| (*name).to_string())
+        .collect();
+
+    for (dir, _) in template_dirs() {
+        let Ok(entries) = fs::read_dir(dir) else {
+            continue;
+        };
+        names.extend(entries.filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "txt" {
+                return None;
+            }
+            Some(path.file_stem()?.to_str()?.to_string())
+        }));
+    }
+
+    names.into_iter().collect()
+}
+
+fn find(name: &str, dirs: Vec<TemplateDir>) -> Result<Template, TemplateError> {
+    // names are joined into paths, they must not point outside of the template directories.
+    if name.is_empty() || name.contains(['/', '\\']) || name.contains("..") {
+        return Err(TemplateError(format!(
+            "invalid template name `{name}`, expected a file name without extension like `grid`."
+        )));
+    }
+
+    for (dir, source) in dirs {
+        let path = dir.join(format!("{name}.txt"));
+        if let Ok(contents) = fs::read_to_string(&path) {
+            return Ok(Template {
+                name: name.to_string(),
+                source: source(path),
+                contents,
+            });
+        }
+    }
+
+    BUILTIN_TEMPLATES
+        .iter()
+        .find(|(builtin, _)| *builtin == name)
+        .map(|(_, contents)| Template {
+            name: name.to_string(),
+            source: TemplateSource::Builtin,
+            contents: (*contents).to_string(),
+        })
+        .ok_or_else(|| {
+            TemplateError(format!(
+                "template `{name}` not found, expected one of {}.",
+                available().join(", ")
+            ))
+        })
+}
+
+/// Looks a template up in the templates directory of the repository, then in the one of the user,
+/// and falls back to the builtin template of the same name.
+pub fn load(name: &str) -> Result<Template, TemplateError> {
+    find(name, template_dirs())
+}
+
+/// The name of the author for the `%AUTHOR%` placeholder, i.e. the git user name.
+pub fn author() -> String {
+    Command::new("git")
+        .args(["config", "user.name"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|name| !name.is_empty())
+        .or_else(|| env::var("USER").ok())
+        .or_else(|| env::var("USERNAME").ok())
+        .unwrap_or_else(|| "unknown".into())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        BUILTIN_TEMPLATES, Placeholders, TemplateDir, TemplateSource, find, placeholders, render,
+    };
+    use crate::{
+        day,
+        template::{PuzzleId, answers::ExampleAnswers},
+    };
+    use std::{env, fs, process, process::Command};
+
+    fn get_mock_placeholders() -> Placeholders {
+        Placeholders::new(
+            PuzzleId::new(2025, day!(8)).unwrap(),
+            Some("Playground".into()),
+            &ExampleAnswers {
+                part_1: Some("40".into()),
+                part_2: None,
+            },
+            "Jane".into(),
+        )
+    }
+
+    #[test]
+    fn finds_placeholders() {
+        let names: Vec<&str> = placeholders("%DAY%: 100% of %YEAR% %x% %%")
+            .into_iter()
+            .map(|(_, name)| name)
+            .collect();
+        assert_eq!(names, vec!["DAY", "YEAR"]);
+    }
+
+    #[test]
+    fn renders_placeholders() {
+        let rendered = render(
+            "solution!(%DAY_NUMBER%); // %DAY% of %YEAR%, %TITLE% on %DATE% by %AUTHOR%",
+            &get_mock_placeholders(),
+        )
+        .unwrap();
+        assert_eq!(
+            rendered,
+            "solution!(8); // 08 of 2025, Day 8: Playground on 2025-12-08 by Jane"
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_placeholders() {
+        let err = render("%DAY% %DAY_NUM% %NAME%", &get_mock_placeholders()).unwrap_err();
+        assert!(err.to_string().contains("%DAY_NUM%, %NAME%"));
+    }
+
+    #[test]
+    fn renders_builtin_templates() {
+        for (name, template) in BUILTIN_TEMPLATES {
+            let rendered = render(template, &get_mock_placeholders());
+            assert!(rendered.is_ok(), "template `{name}`: {rendered:?}");
+            let rendered = rendered.unwrap();
+            assert!(rendered.contains("advent_of_code::solution!(8);"));
+            assert!(rendered.contains("assert_eq!(result, Some(40));"));
+            assert!(rendered.contains("assert_eq!(result, None);"));
+        }
+    }
+
+    #[test]
+    fn falls_back_to_title_of_day() {
+        let values = Placeholders::new(
+            PuzzleId::new(2025, day!(8)).unwrap(),
+            None,
+            &ExampleAnswers::default(),
+            "Jane".into(),
+        );
+        assert_eq!(render("%TITLE%", &values).unwrap(), "Day 8");
+    }
+
+    #[test]
+    fn renders_compiling_tests_for_text_answers() {
+        let answers = ExampleAnswers {
+            part_1: Some("abc".into()),
+            part_2: Some("1,2".into()),
+        };
+        let values = Placeholders::new(
+            PuzzleId::new(2025, day!(8)).unwrap(),
+            None,
+            &answers,
+            "Jane".into(),
+        );
+        let dir = env::temp_dir().join(format!("aoc-answers-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        for (name, contents) in BUILTIN_TEMPLATES {
+            let module = render(contents, &values).unwrap();
+            assert!(module.contains("-> Option<u64>"));
+
+            // compile the assertions of the tests against the return type of the parts.
+            let assertions: Vec<&str> = module
+                .lines()
+                .filter(|line| line.trim_start().starts_with("assert_eq!(result,"))
+                .collect();
+            assert_eq!(assertions.len(), 2);
+            let source = format!(
+                "fn main() {{\n    let result: Option<u64> = None;\n{}\n}}\n",
+                assertions.join("\n")
+            );
+            let path = dir.join(format!("{name}.rs"));
+            fs::write(&path, source).unwrap();
+
+            let output = Command::new(env::var("RUSTC").unwrap_or_else(|_| "rustc".into()))
+                .args(["--edition", "2024", "--emit", "metadata", "--out-dir"])
+                .arg(&dir)
+                .arg(&path)
+                .output()
+                .unwrap();
+            assert!(
+                output.status.success(),
+                "tests of template `{name}` do not compile:\n{}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn looks_up_templates_in_order() {
+        let root = env::temp_dir().join(format!("aoc-templates-{}", process::id()));
+        let (repository, user) = (root.join("repository"), root.join("user"));
+        fs::create_dir_all(&repository).unwrap();
+        fs::create_dir_all(&user).unwrap();
+        fs::write(user.join("grid.txt"), "user grid").unwrap();
+        fs::write(user.join("mine.txt"), "user mine").unwrap();
+        fs::write(repository.join("mine.txt"), "repository mine").unwrap();
+
+        let dirs = || -> Vec<TemplateDir> {
+            vec![
+                (repository.clone(), TemplateSource::Repository),
+                (user.clone(), TemplateSource::User),
+            ]
+        };
+
+        let mine = find("mine", dirs()).unwrap();
+        assert_eq!(mine.contents, "repository mine");
+        assert_eq!(
+            mine.source,
+            TemplateSource::Repository(repository.join("mine.txt"))
+        );
+
+        let grid = find("grid", dirs()).unwrap();
+        assert_eq!(grid.contents, "user grid");
+        assert_eq!(grid.source, TemplateSource::User(user.join("grid.txt")));
+
+        let graph = find("graph", dirs()).unwrap();
+        assert_eq!(graph.source, TemplateSource::Builtin);
+
+        assert!(find("missing", dirs()).is_err());
+        assert!(find("../repository/mine", dirs()).is_err());
+        assert!(find("..", dirs()).is_err());
+        assert!(find("/etc/passwd", dirs()).is_err());
+        assert!(find("sub\\mine", dirs()).is_err());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn user_templates_override_builtin_ones() {
+        let root = env::temp_dir().join(format!("aoc-user-templates-{}", process::id()));
+        // the repository directory does not exist until an override is added, like `.aoc/templates`.
+        let (repository, user) = (root.join("repository"), root.join("user"));
+        fs::create_dir_all(&user).unwrap();
+        fs::write(user.join("default.txt"), "user default").unwrap();
+
+        let dirs: Vec<TemplateDir> = vec![
+            (repository, TemplateSource::Repository),
+            (user.clone(), TemplateSource::User),
+        ];
+        let default = find("default", dirs).unwrap();
+        assert_eq!(default.contents, "user default");
+        assert_eq!(
+            default.source,
+            TemplateSource::User(user.join("default.txt"))
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
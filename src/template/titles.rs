@@ -0,0 +1,169 @@
+/// Keeps the titles of the puzzles of an event, so day numbers can be shown with a name.
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    io::Error,
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, PuzzleId, config::config, puzzle::extract_title};
+
+/// Titles of the puzzles of an event by day, e.g. `Playground` for day 8.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Titles(BTreeMap<Day, String>);
+
+impl Titles {
+    /// Dehydrate the titles of the event in `year` to a JSON file.
+    pub fn store_file(&self, year: u16) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(config().titles_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the titles of the event in `year` from a JSON file. If not present, returns no titles.
+    pub fn read_from_file(year: u16) -> Self {
+        fs::read_to_string(config().titles_path(year))
+            .map_err(|x| x.to_string())
+            .and_then(Titles::try_from)
+            .unwrap_or_default()
+    }
+
+    pub fn get(&self, day: Day) -> Option<&str> {
+        self.0.get(&day).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: Day, title: String) {
+        self.0.insert(day, title);
+    }
+
+    /// The title of a day, falling back to the heading of its downloaded puzzle description.
+    pub fn resolve(&self, id: PuzzleId) -> Option<String> {
+        self.get(id.day())
+            .map(ToString::to_string)
+            .or_else(|| read_title(id))
+    }
+}
+
+/// Strips the day from a puzzle heading, e.g. `Day 8: Playground` becomes `Playground`.
+fn puzzle_name(heading: &str) -> &str {
+    heading
+        .strip_prefix("Day ")
+        .and_then(|rest| rest.split_once(':'))
+        .map_or(heading, |(_, name)| name)
+        .trim()
+}
+
+/// The title in the downloaded puzzle description of a day, if any.
+fn read_title(id: PuzzleId) -> Option<String> {
+    let puzzle = fs::read_to_string(config().puzzle_path(id)).ok()?;
+    extract_title(&puzzle).map(|heading| puzzle_name(&heading).to_string())
+}
+
+/// The title of a day, if it was stored or its puzzle description was downloaded.
+pub fn title(id: PuzzleId) -> Option<String> {
+    Titles::read_from_file(id.year()).resolve(id)
+}
+
+/// Stores the title of a day from its downloaded puzzle description.
+pub fn record(id: PuzzleId) -> Result<Option<String>, Error> {
+    let Some(title) = read_title(id) else {
+        return Ok(None);
+    };
+
+    let mut titles = Titles::read_from_file(id.year());
+    if titles.get(id.day()) != Some(title.as_str()) {
+        titles.insert(id.day(), title.clone());
+        titles.store_file(id.year())?;
+    }
+
+    Ok(Some(title))
+}
+
+/// Names a day in headers, e.g. `Day 08 — Playground`, or `Day 08` if the title is not known.
+pub fn header(day: Day, title: Option<&str>) -> String {
+    match title {
+        Some(title) => format!("Day {day} — {title}"),
+        None => format!("Day {day}"),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Titles> for JsonValue {
+    fn from(value: Titles) -> Self {
+        let map: HashMap<String, JsonValue> = value
+            .0
+            .into_iter()
+            .map(|(day, title)| (day.to_string(), JsonValue::String(title)))
+            .collect();
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Titles {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let map = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        map.iter()
+            .map(|(day, title)| {
+                // NOTE: don't check the event length, like stored timings.
+                let day = day
+                    .parse::<u8>()
+                    .ok()
+                    .and_then(Day::new)
+                    .ok_or_else(|| format!("expected `{day}` to be a day."))?;
+                let title = title
+                    .get::<String>()
+                    .ok_or_else(|| format!("expected the title of day {day} to be a string."))?;
+                Ok((day, title.clone()))
+            })
+            .collect::<Result<_, String>>()
+            .map(Titles)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Titles, header, puzzle_name};
+    use crate::day;
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn strips_day_from_headings() {
+        assert_eq!(puzzle_name("Day 8: Playground"), "Playground");
+        assert_eq!(
+            puzzle_name("Day 12: Christmas Tree Farm"),
+            "Christmas Tree Farm"
+        );
+        assert_eq!(puzzle_name("Playground"), "Playground");
+    }
+
+    #[test]
+    fn formats_headers() {
+        assert_eq!(header(day!(8), Some("Playground")), "Day 08 — Playground");
+        assert_eq!(header(day!(8), None), "Day 08");
+    }
+
+    #[test]
+    fn serializes_titles() {
+        let mut titles = Titles::default();
+        titles.insert(day!(8), "Playground".into());
+        titles.insert(day!(1), "Secret Entrance".into());
+
+        let json = JsonValue::from(titles.clone()).stringify().unwrap();
+        assert_eq!(Titles::try_from(json), Ok(titles));
+        assert!(Titles::try_from(r#"{"x": "Playground"}"#.to_string()).is_err());
+        assert!(Titles::try_from(r#"{"08": 8}"#.to_string()).is_err());
+    }
+}
@@ -0,0 +1,98 @@
+/// Writes a group of files so that either all of them are written or none are.
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+/// Records the changes made to the file system, so they can be undone if a later change fails.
+#[derive(Debug, Default)]
+pub struct Transaction {
+    created_dirs: Vec<PathBuf>,
+    created_files: Vec<PathBuf>,
+    /// Files that were replaced, with their previous contents.
+    replaced_files: Vec<(PathBuf, Vec<u8>)>,
+}
+
+impl Transaction {
+    /// Creates `dir` and those of its parents that do not exist yet.
+    pub fn create_dir_all(&mut self, dir: impl AsRef<Path>) -> io::Result<()> {
+        let mut missing: Vec<PathBuf> = dir
+            .as_ref()
+            .ancestors()
+            .filter(|dir| !dir.as_os_str().is_empty() && !dir.exists())
+            .map(Path::to_path_buf)
+            .collect();
+        missing.reverse();
+
+        for dir in missing {
+            fs::create_dir(&dir)?;
+            self.created_dirs.push(dir);
+        }
+
+        Ok(())
+    }
+
+    /// Writes `contents` to `path`, creating the file and its directories if needed.
+    pub fn write(&mut self, path: impl AsRef<Path>, contents: &str) -> io::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            self.create_dir_all(parent)?;
+        }
+
+        match fs::read(path) {
+            Ok(previous) => {
+                self.replaced_files.push((path.to_path_buf(), previous));
+                fs::write(path, contents)
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                let mut file = OpenOptions::new().write(true).create_new(true).open(path)?;
+                self.created_files.push(path.to_path_buf());
+                file.write_all(contents.as_bytes())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Undoes all changes, in reverse order. Failures are ignored, rolling back is best effort.
+    pub fn rollback(self) {
+        for (path, previous) in self.replaced_files.into_iter().rev() {
+            let _ = fs::write(path, previous);
+        }
+        for path in self.created_files.into_iter().rev() {
+            let _ = fs::remove_file(path);
+        }
+        // only removes directories that are still empty.
+        for dir in self.created_dirs.into_iter().rev() {
+            let _ = fs::remove_dir(dir);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Transaction;
+    use std::{env, fs, process};
+
+    #[test]
+    fn rolls_back_changes() {
+        let root = env::temp_dir().join(format!("aoc-transaction-{}", process::id()));
+        fs::create_dir_all(&root).unwrap();
+        let existing = root.join("existing.txt");
+        fs::write(&existing, "before").unwrap();
+
+        let mut transaction = Transaction::default();
+        transaction.write(&existing, "after").unwrap();
+        transaction.write(root.join("a/b/new.txt"), "new").unwrap();
+        assert_eq!(fs::read_to_string(&existing).unwrap(), "after");
+        assert_eq!(fs::read_to_string(root.join("a/b/new.txt")).unwrap(), "new");
+
+        transaction.rollback();
+        assert_eq!(fs::read_to_string(&existing).unwrap(), "before");
+        assert!(!root.join("a").exists());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,163 @@
+/// Re-runs a solution whenever its source or data files change, by polling their modification times.
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{
+    ANSI_BOLD, ANSI_GREEN, ANSI_ITALIC, ANSI_RED, ANSI_RESET, Part, PuzzleId,
+    config::config,
+    run_multi::child_commands::{parse_part_times, run_solution},
+    runner::RunOptions,
+    titles::{header, title},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+const ANSI_CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// The solution, the library and the example and input files of the puzzle.
+fn watched_files(id: PuzzleId) -> Vec<PathBuf> {
+    let config = config();
+    let mut files = vec![PathBuf::from(config.bin_path(id))];
+
+    // the library is `src/lib.rs` and the modules next to it, e.g. `src/grid.rs`.
+    if let Ok(entries) = fs::read_dir("src") {
+        let mut library: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.extension().is_some_and(|ext| ext == "rs")
+                    && path.file_name().is_some_and(|name| name != "main.rs")
+            })
+            .collect();
+        library.sort_unstable();
+        files.extend(library);
+    }
+
+    // part-specific example files may be added while watching, so list the directories on every poll.
+    for folder in ["examples", "inputs"] {
+        let dir = Path::new(&config.data_dir(id.year())).join(folder);
+        let Ok(entries) = fs::read_dir(dir) else {
+            continue;
+        };
+        let mut data_files: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(&id.day().to_string()))
+            })
+            .collect();
+        data_files.sort_unstable();
+        files.extend(data_files);
+    }
+
+    files
+}
+
+fn snapshot(files: &[PathBuf]) -> Vec<(PathBuf, Option<SystemTime>)> {
+    files
+        .iter()
+        .map(|path| {
+            let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+            (path.clone(), modified)
+        })
+        .collect()
+}
+
+fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos.abs() as u64))
+}
+
+/// Formats the change of a part's duration compared to the previous run, e.g. `-1.2ms (-25.0%)`.
+fn format_delta(previous: f64, current: f64) -> String {
+    let delta = current - previous;
+    let (sign, color) = if delta <= 0.0 {
+        ("-", ANSI_GREEN)
+    } else {
+        ("+", ANSI_RED)
+    };
+    let percent = if previous > 0.0 {
+        format!(" ({sign}{:.1}%)", (delta / previous * 100.0).abs())
+    } else {
+        String::new()
+    };
+    format!("{color}{sign}{}{percent}{ANSI_RESET}", format_nanos(delta))
+}
+
+fn run(id: PuzzleId, options: RunOptions, is_release: bool, files: usize) -> Vec<(Part, f64)> {
+    print!("{ANSI_CLEAR_SCREEN}");
+    println!(
+        "{ANSI_BOLD}{}{ANSI_RESET} {ANSI_ITALIC}(watching {files} files, press Ctrl+C to stop){ANSI_RESET}",
+        header(id.day(), title(id).as_deref())
+    );
+    println!("------");
+
+    match run_solution(id, options, is_release) {
+        Ok(output) if !output.is_empty() => parse_part_times(&output),
+        Ok(_) => {
+            println!("Not solved.");
+            vec![]
+        }
+        Err(e) => {
+            eprintln!("Failed to run solution: {e:?}");
+            vec![]
+        }
+    }
+}
+
+pub fn watch(id: PuzzleId, options: RunOptions, is_release: bool) -> ! {
+    let mut files = watched_files(id);
+    let mut state = snapshot(&files);
+    let mut previous = run(id, options, is_release, files.len());
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        files = watched_files(id);
+        let next_state = snapshot(&files);
+        if next_state == state {
+            continue;
+        }
+        state = next_state;
+
+        let current = run(id, options, is_release, files.len());
+
+        // a failed build or run has no times, keep comparing against the last successful run.
+        if current.is_empty() {
+            continue;
+        }
+
+        if !previous.is_empty() {
+            println!();
+            println!("{ANSI_ITALIC}Compared to the previous run:{ANSI_RESET}");
+            for (part, nanos) in &current {
+                if let Some((_, previous_nanos)) = previous.iter().find(|(p, _)| p == part) {
+                    println!(
+                        "Part {part}: {} → {} {}",
+                        format_nanos(*previous_nanos),
+                        format_nanos(*nanos),
+                        format_delta(*previous_nanos, *nanos)
+                    );
+                }
+            }
+        }
+
+        previous = current;
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_delta, format_nanos};
+
+    #[test]
+    fn formats_deltas() {
+        assert_eq!(format_nanos(1_500_000.0), "1.5ms");
+        assert!(format_delta(2_000_000.0, 1_500_000.0).contains("-500.0µs (-25.0%)"));
+        assert!(format_delta(1_000.0, 1_500.0).contains("+500.0ns (+50.0%)"));
+    }
+}